@@ -1,3 +1,4 @@
+pub mod tokens;
 pub mod types;
 
 use core::fmt;
@@ -0,0 +1,190 @@
+//! One-time tokens to bootstrap enrollment.
+
+use minicbor::{Decode, Encode};
+use ockam_core::CowStr;
+
+#[cfg(feature = "tag")]
+use ockam_core::TypeTag;
+
+use super::types::AttributePair;
+
+/// A token which is redeemed for the attributes it carries
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct OneTimeToken<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] tag: TypeTag<3172640>,
+    /// A short alphanumeric code
+    #[b(1)] pub code: CowStr<'a>,
+    #[b(2)] pub attrs: Vec<AttributePair<'a>>,
+    /// Seconds since the Unix epoch
+    #[n(3)] pub issued_at: u64,
+    /// Seconds since the Unix epoch
+    #[n(4)] pub expires_at: u64,
+    /// How often the token may be redeemed, where 0 means unlimited
+    #[n(5)] pub max_uses: u32,
+}
+
+impl<'a> OneTimeToken<'a> {
+    pub fn new(
+        code: impl Into<CowStr<'a>>,
+        attrs: Vec<AttributePair<'a>>,
+        issued_at: u64,
+        expires_at: u64,
+        max_uses: u32,
+    ) -> Self {
+        OneTimeToken {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            code: code.into(),
+            attrs,
+            issued_at,
+            expires_at,
+            max_uses,
+        }
+    }
+
+    /// Check if the token has not expired at `now` (seconds since the Unix epoch).
+    pub fn is_usable(&self, now: u64) -> bool {
+        now < self.expires_at
+    }
+
+    pub fn is_unlimited(&self) -> bool {
+        self.max_uses == 0
+    }
+
+    /// Check if the token may be redeemed after it has been used `uses` times.
+    pub fn has_uses_left(&self, uses: u32) -> bool {
+        self.is_unlimited() || uses < self.max_uses
+    }
+}
+
+/// Request body to issue a token (`POST /auth/token`)
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct IssueTokenRequest<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] tag: TypeTag<9524481>,
+    #[b(1)] pub attrs: Vec<AttributePair<'a>>,
+    /// How long the token is valid, or the issuer's default if `None`
+    #[n(2)] pub ttl_secs: Option<u64>,
+    /// How often the token may be redeemed, or unlimited if `None`
+    #[n(3)] pub max_uses: Option<u32>,
+}
+
+impl<'a> IssueTokenRequest<'a> {
+    pub fn new(attrs: Vec<AttributePair<'a>>) -> Self {
+        IssueTokenRequest {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            attrs,
+            ttl_secs: None,
+            max_uses: None,
+        }
+    }
+
+    pub fn with_ttl_secs(mut self, ttl: u64) -> Self {
+        self.ttl_secs = Some(ttl);
+        self
+    }
+
+    pub fn with_max_uses(mut self, n: u32) -> Self {
+        self.max_uses = Some(n);
+        self
+    }
+
+    /// Create the token with the given code at `now` (seconds since the Unix epoch).
+    pub fn issue(
+        self,
+        code: impl Into<CowStr<'a>>,
+        now: u64,
+        default_ttl_secs: u64,
+    ) -> OneTimeToken<'a> {
+        let ttl = self.ttl_secs.unwrap_or(default_ttl_secs);
+        OneTimeToken::new(
+            code,
+            self.attrs,
+            now,
+            now.saturating_add(ttl),
+            self.max_uses.unwrap_or(0),
+        )
+    }
+}
+
+/// Response body with an issued token
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct IssueTokenResponse<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] tag: TypeTag<6409817>,
+    #[b(1)] pub token: OneTimeToken<'a>,
+}
+
+impl<'a> IssueTokenResponse<'a> {
+    pub fn new(token: OneTimeToken<'a>) -> Self {
+        IssueTokenResponse {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            token,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attrs() -> Vec<AttributePair<'static>> {
+        vec![AttributePair::new("role", "member")]
+    }
+
+    #[test]
+    fn max_uses() {
+        for req in [
+            IssueTokenRequest::new(attrs()),
+            IssueTokenRequest::new(attrs()).with_max_uses(0),
+        ] {
+            let t = req.issue("a1b2c3", 1000, 600);
+            assert!(t.is_unlimited());
+            assert!(t.has_uses_left(0));
+            assert!(t.has_uses_left(u32::MAX))
+        }
+        let t = IssueTokenRequest::new(attrs())
+            .with_max_uses(2)
+            .issue("a1b2c3", 1000, 600);
+        assert!(!t.is_unlimited());
+        assert!(t.has_uses_left(1));
+        assert!(!t.has_uses_left(2))
+    }
+
+    #[test]
+    fn expiry() {
+        let t = IssueTokenRequest::new(attrs()).issue("a1b2c3", 1000, 600);
+        assert_eq!(1600, t.expires_at);
+        assert!(t.is_usable(1000));
+        assert!(t.is_usable(1599));
+        assert!(!t.is_usable(1600));
+
+        let t = IssueTokenRequest::new(attrs())
+            .with_ttl_secs(u64::MAX)
+            .issue("a1b2c3", 1000, 600);
+        assert_eq!(u64::MAX, t.expires_at);
+        assert!(t.is_usable(u64::MAX - 1))
+    }
+
+    #[test]
+    fn roundtrip() {
+        let req = IssueTokenRequest::new(attrs())
+            .with_ttl_secs(60)
+            .with_max_uses(1);
+        let bytes = minicbor::to_vec(&req).unwrap();
+        assert_eq!(req, minicbor::decode(&bytes).unwrap());
+
+        let res = IssueTokenResponse::new(req.issue("a1b2c3", 1000, 600));
+        let bytes = minicbor::to_vec(&res).unwrap();
+        assert_eq!(res, minicbor::decode(&bytes).unwrap())
+    }
+}
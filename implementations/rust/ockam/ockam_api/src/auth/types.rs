@@ -1,4 +1,6 @@
 use minicbor::{Decode, Encode};
+use ockam_core::{CowBytes, CowStr};
+use serde::Serialize;
 
 #[cfg(feature = "tag")]
 use ockam_core::TypeTag;
@@ -27,3 +29,287 @@ impl<'a> Attribute<'a> {
         self.val
     }
 }
+
+/// A key-value pair of textual metadata
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, Serialize)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct AttributePair<'a> {
+    #[cfg(feature = "tag")]
+    #[serde(skip)]
+    #[cbor(n(0))]
+    tag: TypeTag<2870551>,
+    #[b(1)] pub key: CowStr<'a>,
+    #[b(2)] pub value: CowStr<'a>,
+}
+
+impl<'a> AttributePair<'a> {
+    pub fn new(key: impl Into<CowStr<'a>>, value: impl Into<CowStr<'a>>) -> Self {
+        AttributePair {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            key: key.into(),
+            value: value.into(),
+        }
+    }
+}
+
+/// A signed credential as exchanged between identities
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct Credential<'a> {
+    #[cfg(feature = "tag")]
+    #[cbor(n(0))]
+    tag: TypeTag<3914027>,
+    /// The signed CBOR encoding of the `CredentialData`
+    #[b(1)] pub credential: CowBytes<'a>,
+    #[b(2)] pub purpose_key_attestation: Option<CowBytes<'a>>,
+}
+
+impl<'a> Credential<'a> {
+    pub fn new(
+        credential: impl Into<CowBytes<'a>>,
+        purpose_key_attestation: Option<CowBytes<'a>>,
+    ) -> Self {
+        Credential {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            credential: credential.into(),
+            purpose_key_attestation,
+        }
+    }
+}
+
+/// The contents of a signed credential
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct CredentialData<'a> {
+    #[cfg(feature = "tag")]
+    #[cbor(n(0))]
+    tag: TypeTag<7504251>,
+    #[b(1)] pub subject: CowStr<'a>,
+    #[b(2)] pub issuer: CowStr<'a>,
+    /// Issue time in seconds since the Unix epoch
+    #[n(3)] pub created_at: u64,
+    /// Expiry time in seconds since the Unix epoch
+    #[n(4)] pub expires_at: u64,
+    #[b(5)] pub attributes: Vec<AttributePair<'a>>,
+}
+
+impl<'a> CredentialData<'a> {
+    pub fn new(
+        subject: impl Into<CowStr<'a>>,
+        issuer: impl Into<CowStr<'a>>,
+        created_at: u64,
+        expires_at: u64,
+        attributes: Vec<AttributePair<'a>>,
+    ) -> Self {
+        CredentialData {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            subject: subject.into(),
+            issuer: issuer.into(),
+            created_at,
+            expires_at,
+            attributes,
+        }
+    }
+
+    /// Check if the credential has expired at `now` (seconds since the Unix epoch).
+    pub fn is_expired(&self, now: u64) -> bool {
+        now >= self.expires_at
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct PresentCredentialRequest<'a> {
+    #[cfg(feature = "tag")]
+    #[cbor(n(0))]
+    tag: TypeTag<2205683>,
+    #[b(1)] pub credentials: Vec<Credential<'a>>,
+}
+
+impl<'a> PresentCredentialRequest<'a> {
+    pub fn new(credentials: Vec<Credential<'a>>) -> Self {
+        PresentCredentialRequest {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            credentials,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct VerifyCredentialRequest<'a> {
+    #[cfg(feature = "tag")]
+    #[cbor(n(0))]
+    tag: TypeTag<6650348>,
+    #[b(1)] pub credential: Credential<'a>,
+    /// The identity the credential is expected to be about
+    #[b(2)] pub subject: CowStr<'a>,
+}
+
+impl<'a> VerifyCredentialRequest<'a> {
+    pub fn new(credential: Credential<'a>, subject: impl Into<CowStr<'a>>) -> Self {
+        VerifyCredentialRequest {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            credential,
+            subject: subject.into(),
+        }
+    }
+}
+
+/// The attributes stored for an identity
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct AttributesEntry<'a> {
+    #[cfg(feature = "tag")]
+    #[cbor(n(0))]
+    tag: TypeTag<4839617>,
+    #[b(1)] pub attrs: Vec<AttributePair<'a>>,
+    /// The identity that added the attributes
+    #[b(2)] pub added_by: CowStr<'a>,
+    /// Expiry time in seconds since the Unix epoch
+    #[n(3)] pub expires_at: Option<u64>,
+    /// The identity that attested the attributes, if they were not added locally
+    #[b(4)] pub attested_by: Option<CowStr<'a>>,
+}
+
+impl<'a> AttributesEntry<'a> {
+    pub fn new(
+        attrs: Vec<AttributePair<'a>>,
+        added_by: impl Into<CowStr<'a>>,
+        expires_at: Option<u64>,
+        attested_by: Option<CowStr<'a>>,
+    ) -> Self {
+        AttributesEntry {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            attrs,
+            added_by: added_by.into(),
+            expires_at,
+            attested_by,
+        }
+    }
+
+    /// Check if the entry is still valid at `now` (seconds since the Unix epoch).
+    pub fn is_valid(&self, now: u64) -> bool {
+        match self.expires_at {
+            Some(t) => now < t,
+            None => true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct SetAttributesRequest<'a> {
+    #[cfg(feature = "tag")]
+    #[cbor(n(0))]
+    tag: TypeTag<1962480>,
+    #[b(1)] pub identifier: CowStr<'a>,
+    #[b(2)] pub attrs: Vec<AttributePair<'a>>,
+}
+
+impl<'a> SetAttributesRequest<'a> {
+    pub fn new(identifier: impl Into<CowStr<'a>>, attrs: Vec<AttributePair<'a>>) -> Self {
+        SetAttributesRequest {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            identifier: identifier.into(),
+            attrs,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct GetAttributesResponse<'a> {
+    #[cfg(feature = "tag")]
+    #[cbor(n(0))]
+    tag: TypeTag<8207315>,
+    #[b(1)] pub entry: AttributesEntry<'a>,
+}
+
+impl<'a> GetAttributesResponse<'a> {
+    pub fn new(entry: AttributesEntry<'a>) -> Self {
+        GetAttributesResponse {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            entry,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn credential_data_is_expired() {
+        let data = CredentialData::new("P1", "P2", 1000, 2000, vec![]);
+        assert!(!data.is_expired(1000));
+        assert!(!data.is_expired(1999));
+        assert!(data.is_expired(2000));
+        assert!(data.is_expired(2001));
+    }
+
+    #[test]
+    fn credential_with_attestation_is_longer() {
+        let blob: &[u8] = &[1, 2, 3, 4];
+        let without = Credential::new(blob, None);
+        let with = Credential::new(blob, Some(CowBytes::from(&[5u8, 6, 7][..])));
+
+        let a = minicbor::to_vec(&without).unwrap();
+        let b = minicbor::to_vec(&with).unwrap();
+        assert!(a.len() < b.len());
+
+        assert_eq!(without, minicbor::decode(&a).unwrap());
+        assert_eq!(with, minicbor::decode(&b).unwrap());
+    }
+
+    #[test]
+    fn verify_credential_request_roundtrip() {
+        let data = CredentialData::new(
+            "P1",
+            "P2",
+            1000,
+            2000,
+            vec![AttributePair::new("role", "member")],
+        );
+        let bytes = minicbor::to_vec(&data).unwrap();
+        let req = VerifyCredentialRequest::new(Credential::new(bytes.as_slice(), None), "P1");
+        let enc = minicbor::to_vec(&req).unwrap();
+        let dec: VerifyCredentialRequest = minicbor::decode(&enc).unwrap();
+        assert_eq!(req, dec);
+        let inner: CredentialData = minicbor::decode(dec.credential.credential.as_slice()).unwrap();
+        assert_eq!(data, inner);
+    }
+
+    #[test]
+    fn attributes_entry_is_valid() {
+        let attrs = vec![AttributePair::new("role", "member")];
+
+        let entry = AttributesEntry::new(attrs.clone(), "P1", None, None);
+        assert!(entry.is_valid(u64::MAX));
+
+        let entry = AttributesEntry::new(attrs, "P1", Some(2000), Some("P2".into()));
+        assert!(entry.is_valid(1999));
+        assert!(!entry.is_valid(2000));
+        assert!(!entry.is_valid(2001));
+
+        let res = GetAttributesResponse::new(entry);
+        let bytes = minicbor::to_vec(&res).unwrap();
+        assert_eq!(res, minicbor::decode(&bytes).unwrap());
+    }
+}
@@ -0,0 +1,165 @@
+//! Sending requests and receiving responses.
+//!
+//! The [`Client`] trait abstracts over the transport used to deliver an
+//! encoded request and to obtain the encoded response. Implementing it for
+//! a real transport only requires moving bytes, e.g.:
+//!
+//! ```ignore
+//! struct TcpClient(std::net::TcpStream);
+//!
+//! impl Client for TcpClient {
+//!     fn send<T: Encode<()>>(&mut self, req: RequestBuilder<T>) -> Result<(Response, Vec<u8>), ClientError> {
+//!         let bytes = req.to_vec()?;
+//!         self.0.write_all(&Frame::encode(&bytes)).map_err(ClientError::transport)?;
+//!         let reply = read_frame(&mut self.0).map_err(ClientError::transport)?;
+//!         client::decode_response(&reply)
+//!     }
+//! }
+//! ```
+
+pub mod mock;
+pub mod queue;
+pub mod retry;
+
+pub use queue::RequestQueue;
+pub use retry::{BackoffStrategy, RetryPolicy};
+
+use core::fmt;
+use minicbor::encode::Write;
+use minicbor::{decode, encode, Encode};
+use ockam_core::api::{parse_response, RequestBuilder, Response};
+
+/// A client sends requests and returns the response header and body bytes.
+pub trait Client {
+    fn send<T: Encode<()>>(
+        &mut self,
+        req: RequestBuilder<T>,
+    ) -> Result<(Response, Vec<u8>), ClientError>;
+}
+
+/// Decode a response header and return it together with the remaining bytes.
+pub fn decode_response(bytes: &[u8]) -> Result<(Response, Vec<u8>), ClientError> {
+    let (res, body) = parse_response(bytes)?;
+    Ok((res, body.to_vec()))
+}
+
+/// A client which passes encoded requests directly to a handler function,
+/// e.g. [`Router::dispatch`](crate::routing::Router::dispatch).
+///
+/// Useful for testing without network I/O.
+pub struct LoopbackClient<F> {
+    handler: F,
+}
+
+impl<F> LoopbackClient<F>
+where
+    F: FnMut(&[u8]) -> ockam_core::Result<Vec<u8>>,
+{
+    pub fn new(handler: F) -> Self {
+        LoopbackClient { handler }
+    }
+}
+
+impl<F> Client for LoopbackClient<F>
+where
+    F: FnMut(&[u8]) -> ockam_core::Result<Vec<u8>>,
+{
+    fn send<T: Encode<()>>(
+        &mut self,
+        req: RequestBuilder<T>,
+    ) -> Result<(Response, Vec<u8>), ClientError> {
+        let bytes = req.to_vec()?;
+        let reply = (self.handler)(&bytes).map_err(ClientError::transport)?;
+        decode_response(&reply)
+    }
+}
+
+/// Errors which may occur when sending a request.
+#[derive(Debug)]
+pub enum ClientError {
+    /// The request could not be encoded.
+    Encode(encode::Error<<Vec<u8> as Write>::Error>),
+    /// The response could not be decoded.
+    Decode(decode::Error),
+    /// The underlying transport failed.
+    Transport(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl ClientError {
+    pub fn transport<E>(e: E) -> Self
+    where
+        E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        ClientError::Transport(e.into())
+    }
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::Encode(e) => write!(f, "failed to encode request: {e}"),
+            ClientError::Decode(e) => write!(f, "failed to decode response: {e}"),
+            ClientError::Transport(e) => write!(f, "transport error: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ClientError::Encode(e) => Some(e),
+            ClientError::Decode(e) => Some(e),
+            ClientError::Transport(e) => Some(&**e),
+        }
+    }
+}
+
+impl From<encode::Error<<Vec<u8> as Write>::Error>> for ClientError {
+    fn from(e: encode::Error<<Vec<u8> as Write>::Error>) -> Self {
+        ClientError::Encode(e)
+    }
+}
+
+impl From<decode::Error> for ClientError {
+    fn from(e: decode::Error) -> Self {
+        ClientError::Decode(e)
+    }
+}
+
+impl From<ClientError> for ockam_core::Error {
+    fn from(e: ClientError) -> Self {
+        use ockam_core::errcode::{Kind, Origin};
+        ockam_core::Error::new(Origin::Application, Kind::Protocol, e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::routing::{PathParams, Router};
+    use ockam_core::api::{Method, Request, Status};
+
+    type Handler = fn(&Request, PathParams, Option<&[u8]>) -> ockam_core::Result<Vec<u8>>;
+
+    fn echo(r: &Request, _: PathParams, b: Option<&[u8]>) -> ockam_core::Result<Vec<u8>> {
+        let body: &str = minicbor::decode(b.unwrap_or_default())?;
+        Ok(Response::ok(r.id()).body(body).to_vec()?)
+    }
+
+    #[test]
+    fn loopback() {
+        let mut router = Router::<Handler>::new();
+        router.add(Method::Post, "/echo", echo).unwrap();
+        let mut client = LoopbackClient::new(|b: &[u8]| router.dispatch(b));
+
+        let req = Request::post("/echo").body("hello");
+        let id = req.header().id();
+        let (res, body) = client.send(req).unwrap();
+        assert_eq!(id, res.re());
+        assert_eq!(Some(Status::Ok), res.status());
+        assert_eq!("hello", res.decode_body::<&str>(&body).unwrap());
+
+        let (res, _) = client.send(Request::get("/nothing")).unwrap();
+        assert_eq!(Some(Status::NotFound), res.status())
+    }
+}
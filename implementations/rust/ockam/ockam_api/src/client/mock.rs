@@ -0,0 +1,99 @@
+//! A [`Client`] returning pre-programmed responses.
+//!
+//! ```
+//! use ockam_api::client::mock::MockClient;
+//! use ockam_api::client::Client;
+//! use ockam_core::api::{Request, Status};
+//!
+//! let mut mock = MockClient::new();
+//! mock.expect(Status::Created, minicbor::to_vec("n1").unwrap());
+//! mock.expect(Status::Ok, minicbor::to_vec("n1").unwrap());
+//! mock.expect(Status::NoContent, Vec::new());
+//!
+//! let (res, body) = mock.send(Request::post("/nodes").body("n1")).unwrap();
+//! assert_eq!(Some(Status::Created), res.status());
+//! assert_eq!("n1", res.decode_body::<&str>(&body).unwrap());
+//!
+//! let (res, _) = mock.send(Request::get("/nodes/n1")).unwrap();
+//! assert_eq!(Some(Status::Ok), res.status());
+//!
+//! let (res, _) = mock.send(Request::delete("/nodes/n1")).unwrap();
+//! assert_eq!(Some(Status::NoContent), res.status());
+//! assert!(!res.has_body());
+//!
+//! mock.assert_request_count(3);
+//! assert_eq!("/nodes/n1", mock.recorded_request(2).path());
+//! ```
+
+use super::{decode_response, Client, ClientError};
+use minicbor::Encode;
+use ockam_core::api::{parse_request, Cbor, Request, RequestBuilder, Response, Status};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+/// A client which records all requests and answers them with responses
+/// enqueued via [`MockClient::expect`].
+///
+/// Clones share their expectations and recorded requests. If no response
+/// is enqueued, requests are answered with an internal server error.
+#[derive(Debug, Clone, Default)]
+pub struct MockClient {
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    responses: VecDeque<(Status, Vec<u8>)>,
+    requests: Vec<Request<'static>>,
+}
+
+impl MockClient {
+    pub fn new() -> Self {
+        MockClient::default()
+    }
+
+    /// Enqueue a response with the given status and CBOR-encoded body.
+    ///
+    /// An empty body results in a response without body.
+    pub fn expect(&self, status: Status, body: Vec<u8>) {
+        self.state().responses.push_back((status, body))
+    }
+
+    /// Get a copy of the i-th recorded request header.
+    ///
+    /// # Panics
+    ///
+    /// If fewer than `i + 1` requests have been recorded.
+    pub fn recorded_request(&self, i: usize) -> Request<'static> {
+        self.state().requests[i].clone()
+    }
+
+    /// Assert that exactly `n` requests have been recorded.
+    #[track_caller]
+    pub fn assert_request_count(&self, n: usize) {
+        assert_eq!(n, self.state().requests.len(), "number of requests")
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Client for MockClient {
+    fn send<T: Encode<()>>(
+        &mut self,
+        req: RequestBuilder<T>,
+    ) -> Result<(Response, Vec<u8>), ClientError> {
+        let bytes = req.to_vec()?;
+        let (header, _) = parse_request(&bytes)?;
+        let id = header.id();
+        let mut state = self.state();
+        state.requests.push(header.into_owned());
+        let reply = match state.responses.pop_front() {
+            Some((status, body)) if body.is_empty() => Response::builder(id, status).to_vec()?,
+            Some((status, body)) => Response::builder(id, status).body(Cbor(&body)).to_vec()?,
+            None => Response::internal_error(id).to_vec()?,
+        };
+        decode_response(&reply)
+    }
+}
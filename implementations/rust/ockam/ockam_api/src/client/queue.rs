@@ -0,0 +1,127 @@
+//! Matching responses to requests which are in flight concurrently.
+
+use ockam_core::api::{Id, RequestBuilder, Response};
+use ockam_node::tokio::sync::oneshot;
+use std::collections::HashMap;
+
+/// A request waiting for its response.
+#[derive(Debug)]
+pub struct PendingRequest<'a, T> {
+    request: RequestBuilder<'a, T>,
+    reply: oneshot::Sender<Response>,
+}
+
+impl<'a, T> PendingRequest<'a, T> {
+    /// The original request, e.g. to retransmit it.
+    pub fn request(&self) -> &RequestBuilder<'a, T> {
+        &self.request
+    }
+}
+
+/// Pending requests keyed by their ID.
+///
+/// Responses passed to [`RequestQueue::receive`] are delivered to the
+/// receiver returned by [`RequestQueue::send`] for the request they
+/// answer, regardless of the order in which they arrive.
+#[derive(Debug)]
+pub struct RequestQueue<'a, T = ()> {
+    pending: HashMap<Id, PendingRequest<'a, T>>,
+}
+
+impl<'a, T> Default for RequestQueue<'a, T> {
+    fn default() -> Self {
+        RequestQueue {
+            pending: HashMap::new(),
+        }
+    }
+}
+
+impl<'a, T> RequestQueue<'a, T> {
+    pub fn new() -> Self {
+        RequestQueue::default()
+    }
+
+    /// Register a request which is about to be sent.
+    ///
+    /// Returns the request ID and a receiver for the response.
+    pub fn send(&mut self, request: RequestBuilder<'a, T>) -> (Id, oneshot::Receiver<Response>) {
+        let id = request.header().id();
+        let (tx, rx) = oneshot::channel();
+        let p = PendingRequest { request, reply: tx };
+        self.pending.insert(id, p);
+        (id, rx)
+    }
+
+    /// Deliver a response to the receiver of its request.
+    ///
+    /// Returns `false` if no request with the response's `re` ID is pending,
+    /// in which case the response is dropped.
+    pub fn receive(&mut self, response: Response) -> bool {
+        if let Some(p) = self.pending.remove(&response.re()) {
+            // The receiver may have been dropped already, which is fine.
+            let _ = p.reply.send(response);
+            true
+        } else {
+            warn!(re = %response.re(), "received response to unknown request");
+            false
+        }
+    }
+
+    /// Stop waiting for a response to the given request.
+    pub fn cancel(&mut self, id: Id) -> Option<PendingRequest<'a, T>> {
+        self.pending.remove(&id)
+    }
+
+    /// Get the pending request with the given ID.
+    pub fn get(&self, id: Id) -> Option<&PendingRequest<'a, T>> {
+        self.pending.get(&id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ockam_core::api::{Request, Status};
+
+    #[test]
+    fn out_of_order_responses() {
+        let mut q = RequestQueue::new();
+        let (a, mut rx_a) = q.send(Request::get("/a"));
+        let (b, mut rx_b) = q.send(Request::get("/b"));
+        assert_eq!(2, q.len());
+        assert_eq!("/b", q.get(b).unwrap().request().header().path());
+
+        assert!(q.receive(Response::new(b, Status::NotFound, false)));
+        assert!(rx_a.try_recv().is_err());
+        assert!(q.receive(Response::new(a, Status::Ok, false)));
+        assert!(q.is_empty());
+
+        let res = rx_a.try_recv().unwrap();
+        assert_eq!(a, res.re());
+        assert_eq!(Some(Status::Ok), res.status());
+        let res = rx_b.try_recv().unwrap();
+        assert_eq!(b, res.re());
+        assert_eq!(Some(Status::NotFound), res.status())
+    }
+
+    #[test]
+    fn unknown_and_cancelled() {
+        let mut q = RequestQueue::new();
+        let (id, mut rx) = q.send(Request::get("/a"));
+        assert!(!q.receive(Response::new(Id::fresh(), Status::Ok, false)));
+        assert_eq!(1, q.len());
+
+        assert!(q.cancel(id).is_some());
+        assert!(rx.try_recv().is_err());
+        assert!(!q.receive(Response::new(id, Status::Ok, false)));
+        assert!(q.is_empty())
+    }
+}
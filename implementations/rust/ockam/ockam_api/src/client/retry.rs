@@ -0,0 +1,157 @@
+//! When and how long to wait before resending a failed request.
+
+use core::time::Duration;
+use ockam_core::api::{Method, Response, Status};
+
+/// How the delay between attempts evolves.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BackoffStrategy {
+    /// Always wait the same amount of time.
+    Constant(Duration),
+    /// Wait `n` times the given duration before the `n`-th retry.
+    Linear(Duration),
+    /// Multiply the delay by `factor` after every retry, up to `max`.
+    Exponential {
+        initial: Duration,
+        factor: f64,
+        max: Duration,
+    },
+}
+
+impl BackoffStrategy {
+    /// The delay before the `n`-th retry, starting at 1.
+    pub fn delay(&self, n: u32) -> Duration {
+        let n = n.max(1);
+        match self {
+            BackoffStrategy::Constant(d) => *d,
+            BackoffStrategy::Linear(d) => d.saturating_mul(n),
+            BackoffStrategy::Exponential {
+                initial,
+                factor,
+                max,
+            } => {
+                let secs = initial.as_secs_f64() * factor.powf(f64::from(n - 1));
+                Duration::try_from_secs_f64(secs)
+                    .map(|d| d.min(*max))
+                    .unwrap_or(*max)
+            }
+        }
+    }
+}
+
+/// Decides if a request should be sent again after a failed response.
+///
+/// Only requests with idempotent methods are retried, since resending
+/// e.g. a `POST` may apply its effect more than once.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// The maximum number of attempts, including the first one.
+    pub max_attempts: u32,
+    pub backoff: BackoffStrategy,
+    pub retryable_statuses: Vec<Status>,
+}
+
+impl Default for RetryPolicy {
+    /// Make up to 3 attempts on "503 Service Unavailable" and
+    /// "504 Gateway Timeout", waiting 100ms, 200ms, ... up to 5s.
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            backoff: BackoffStrategy::Exponential {
+                initial: Duration::from_millis(100),
+                factor: 2.0,
+                max: Duration::from_secs(5),
+            },
+            retryable_statuses: vec![Status::ServiceUnavailable, Status::GatewayTimeout],
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Should a request with the given method be retried?
+    ///
+    /// `res` is the response to the `attempt`-th attempt, starting at 1.
+    pub fn should_retry(&self, res: &Response, method: Method, attempt: u32) -> bool {
+        attempt < self.max_attempts
+            && method.is_idempotent()
+            && res
+                .status()
+                .map(|s| self.retryable_statuses.contains(&s))
+                .unwrap_or(false)
+    }
+
+    /// The delay before resending a request after the `attempt`-th attempt.
+    pub fn delay(&self, attempt: u32) -> Duration {
+        self.backoff.delay(attempt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::mock::MockClient;
+    use crate::client::Client;
+    use ockam_core::api::{Id, Request};
+
+    #[test]
+    fn retry_until_ok() {
+        let policy = RetryPolicy::default();
+        let mut mock = MockClient::new();
+        mock.expect(Status::ServiceUnavailable, Vec::new());
+        mock.expect(Status::ServiceUnavailable, Vec::new());
+        mock.expect(Status::Ok, minicbor::to_vec("n1").unwrap());
+
+        let mut delays = Vec::new();
+        let mut attempt = 1;
+        let (res, body) = loop {
+            let (res, body) = mock.send(Request::get("/nodes/n1")).unwrap();
+            if !policy.should_retry(&res, Method::Get, attempt) {
+                break (res, body);
+            }
+            delays.push(policy.delay(attempt));
+            attempt += 1
+        };
+
+        assert_eq!(Some(Status::Ok), res.status());
+        assert_eq!("n1", res.decode_body::<&str>(&body).unwrap());
+        assert_eq!(
+            vec![Duration::from_millis(100), Duration::from_millis(200)],
+            delays
+        );
+        mock.assert_request_count(3)
+    }
+
+    #[test]
+    fn no_retry() {
+        let policy = RetryPolicy::default();
+        let unavailable = Response::service_unavailable(Id::ZERO).into_parts().0;
+        let timeout = Response::gateway_timeout(Id::ZERO).into_parts().0;
+        let internal = Response::internal_error(Id::ZERO).into_parts().0;
+
+        assert!(policy.should_retry(&unavailable, Method::Put, 1));
+        assert!(policy.should_retry(&timeout, Method::Delete, 2));
+        // Non-idempotent methods:
+        assert!(!policy.should_retry(&unavailable, Method::Post, 1));
+        assert!(!policy.should_retry(&unavailable, Method::Patch, 1));
+        // Status not retryable:
+        assert!(!policy.should_retry(&internal, Method::Get, 1));
+        // Attempts exhausted:
+        assert!(!policy.should_retry(&unavailable, Method::Get, 3))
+    }
+
+    #[test]
+    fn backoff() {
+        let d = Duration::from_millis(10);
+        assert_eq!(d, BackoffStrategy::Constant(d).delay(5));
+        assert_eq!(d * 3, BackoffStrategy::Linear(d).delay(3));
+        let e = BackoffStrategy::Exponential {
+            initial: d,
+            factor: 3.0,
+            max: Duration::from_millis(100),
+        };
+        assert_eq!(d, e.delay(1));
+        assert_eq!(d * 9, e.delay(3));
+        assert_eq!(Duration::from_millis(100), e.delay(4));
+        assert_eq!(Duration::from_millis(100), e.delay(u32::MAX))
+    }
+}
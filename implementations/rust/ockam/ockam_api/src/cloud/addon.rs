@@ -0,0 +1,162 @@
+use core::fmt;
+
+use minicbor::{Decode, Encode};
+
+#[cfg(feature = "tag")]
+use ockam_core::TypeTag;
+use ockam_core::{CowBytes, CowStr};
+
+use crate::pagination::PaginatedResponse;
+
+/// Configuration of an addon.
+///
+/// The controller treats this as an opaque CBOR blob for now, so the bytes
+/// are passed through unchanged.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[cbor(transparent)]
+pub struct AddonConfig<'a>(#[b(0)] pub CowBytes<'a>);
+
+impl<'a> AddonConfig<'a> {
+    pub fn new(bytes: impl Into<CowBytes<'a>>) -> Self {
+        Self(bytes.into())
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+}
+
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct AddonInfo<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] pub tag: TypeTag<6271804>,
+    #[b(1)] pub id: CowStr<'a>,
+    #[b(2)] pub description: CowStr<'a>,
+    #[n(3)] pub is_enabled: bool,
+    #[b(4)] pub config: Option<AddonConfig<'a>>,
+}
+
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct EnableAddonRequest<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] pub tag: TypeTag<3081469>,
+    #[b(1)] pub addon_id: CowStr<'a>,
+    #[b(2)] pub config: CowBytes<'a>,
+}
+
+impl<'a> EnableAddonRequest<'a> {
+    pub fn new(addon_id: impl Into<CowStr<'a>>, config: impl Into<CowBytes<'a>>) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            addon_id: addon_id.into(),
+            config: config.into(),
+        }
+    }
+}
+
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct DisableAddonRequest<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] pub tag: TypeTag<8824315>,
+    #[b(1)] pub addon_id: CowStr<'a>,
+}
+
+impl<'a> DisableAddonRequest<'a> {
+    pub fn new(addon_id: impl Into<CowStr<'a>>) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            addon_id: addon_id.into(),
+        }
+    }
+}
+
+pub type ListAddonsResponse<'a> = PaginatedResponse<'a, AddonInfo<'a>>;
+
+/// An invalid addon configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ConfigError {
+    /// The tenant base URL does not use HTTPS.
+    InsecureTenantUrl,
+    /// The certificate is empty.
+    MissingCertificate,
+    /// The bootstrap server is not of the form `host:port`.
+    InvalidBootstrapServer,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::InsecureTenantUrl => {
+                f.write_str("tenant base url must start with https://")
+            }
+            ConfigError::MissingCertificate => f.write_str("tenant certificate is empty"),
+            ConfigError::InvalidBootstrapServer => {
+                f.write_str("bootstrap server must be of the form host:port")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A CBOR map `{"bucket": "metrics"}`, which must survive untouched.
+    const CONFIG: &[u8] = &[
+        0xa1, 0x66, 0x62, 0x75, 0x63, 0x6b, 0x65, 0x74, 0x67, 0x6d, 0x65, 0x74, 0x72, 0x69, 0x63,
+        0x73,
+    ];
+
+    #[test]
+    fn enable_disable_roundtrip() {
+        let req = EnableAddonRequest::new("influxdb", CONFIG);
+        let bytes = minicbor::to_vec(&req).unwrap();
+        let dec: EnableAddonRequest = minicbor::decode(&bytes).unwrap();
+        assert_eq!(req, dec);
+        assert_eq!(CONFIG, dec.config.as_slice());
+
+        let req = DisableAddonRequest::new("influxdb");
+        let bytes = minicbor::to_vec(&req).unwrap();
+        assert_eq!(req, minicbor::decode(&bytes).unwrap());
+    }
+
+    #[test]
+    fn opaque_config_is_preserved() {
+        let res = ListAddonsResponse::complete(vec![
+            AddonInfo {
+                #[cfg(feature = "tag")]
+                tag: TypeTag,
+                id: "influxdb".into(),
+                description: "InfluxDB".into(),
+                is_enabled: true,
+                config: Some(AddonConfig::new(CONFIG)),
+            },
+            AddonInfo {
+                #[cfg(feature = "tag")]
+                tag: TypeTag,
+                id: "okta".into(),
+                description: "Okta".into(),
+                is_enabled: false,
+                config: None,
+            },
+        ]);
+        let bytes = minicbor::to_vec(&res).unwrap();
+        let dec: ListAddonsResponse = minicbor::decode(&bytes).unwrap();
+        assert_eq!(res, dec);
+        assert_eq!(
+            Some(CONFIG),
+            dec.items[0].config.as_ref().map(|c| c.as_bytes())
+        );
+    }
+}
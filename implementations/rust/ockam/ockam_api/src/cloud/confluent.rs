@@ -0,0 +1,129 @@
+use minicbor::{Decode, Encode};
+
+use ockam_core::CowStr;
+#[cfg(feature = "tag")]
+use ockam_core::TypeTag;
+
+use crate::cloud::addon::ConfigError;
+
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct ConfluentConfig<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] pub tag: TypeTag<1570428>,
+    /// The Kafka bootstrap server as `host:port`
+    #[b(1)] pub bootstrap_server: CowStr<'a>,
+    #[b(2)] pub sasl_username: Option<CowStr<'a>>,
+}
+
+impl<'a> ConfluentConfig<'a> {
+    pub fn new(bootstrap_server: impl Into<CowStr<'a>>) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            bootstrap_server: bootstrap_server.into(),
+            sasl_username: None,
+        }
+    }
+
+    pub fn with_sasl_username(mut self, username: impl Into<CowStr<'a>>) -> Self {
+        self.sasl_username = Some(username.into());
+        self
+    }
+
+    /// Check that the bootstrap server is of the form `host:port`.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.bootstrap_server.matches(':').count() != 1 {
+            return Err(ConfigError::InvalidBootstrapServer);
+        }
+        Ok(())
+    }
+}
+
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct CreateConfluentAddonRequest<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] pub tag: TypeTag<4937716>,
+    #[b(1)] pub project_id: CowStr<'a>,
+    #[b(2)] pub config: ConfluentConfig<'a>,
+}
+
+impl<'a> CreateConfluentAddonRequest<'a> {
+    pub fn new(project_id: impl Into<CowStr<'a>>, config: ConfluentConfig<'a>) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            project_id: project_id.into(),
+            config,
+        }
+    }
+}
+
+/// A short-lived Kafka API key.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct KafkaApiKeyLease<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] pub tag: TypeTag<8281350>,
+    #[b(1)] pub id: CowStr<'a>,
+    #[b(2)] pub api_key: CowStr<'a>,
+    #[b(3)] pub api_secret: CowStr<'a>,
+    /// Seconds since the Unix epoch
+    #[n(4)] pub expires_at: u64,
+}
+
+impl<'a> KafkaApiKeyLease<'a> {
+    /// Check if the lease has expired at `now` (seconds since the Unix epoch).
+    pub fn is_expired(&self, now: u64) -> bool {
+        now >= self.expires_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate() {
+        assert_eq!(
+            Ok(()),
+            ConfluentConfig::new("kafka.example:9092").validate()
+        );
+        for s in ["kafka.example", "kafka.example:9092:1", "[::1]:9092"] {
+            assert_eq!(
+                Err(ConfigError::InvalidBootstrapServer),
+                ConfluentConfig::new(s).validate()
+            )
+        }
+    }
+
+    #[test]
+    fn create_request() {
+        let c = ConfluentConfig::new("kafka.example:9092").with_sasl_username("alice");
+        let req = CreateConfluentAddonRequest::new("p1", c);
+        let bytes = minicbor::to_vec(&req).unwrap();
+        assert_eq!(req, minicbor::decode(&bytes).unwrap());
+    }
+
+    #[test]
+    fn lease() {
+        let lease = KafkaApiKeyLease {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            id: "l1".into(),
+            api_key: "KEY".into(),
+            api_secret: "  s3cr3t/+= \n".into(),
+            expires_at: 2000,
+        };
+        let bytes = minicbor::to_vec(&lease).unwrap();
+        let dec: KafkaApiKeyLease = minicbor::decode(&bytes).unwrap();
+        assert_eq!(lease, dec);
+        assert_eq!(dec.api_secret.as_bytes(), b"  s3cr3t/+= \n");
+        assert!(!dec.is_expired(1999));
+        assert!(dec.is_expired(2000));
+    }
+}
@@ -0,0 +1,115 @@
+use minicbor::{Decode, Encode};
+use serde::Serialize;
+
+use ockam_core::CowStr;
+#[cfg(feature = "tag")]
+use ockam_core::TypeTag;
+
+/// The controller path to verify an email address
+pub fn verify_email_path() -> &'static str {
+    "/v0/verify-email"
+}
+
+/// The number of codes which may be tried before a new one has to be sent.
+pub const MAX_VERIFICATION_ATTEMPTS: u32 = 5;
+
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct VerifyEmailRequest<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] pub tag: TypeTag<5720613>,
+    #[b(1)] pub verification_code: CowStr<'a>,
+    #[b(2)] pub email: CowStr<'a>,
+}
+
+impl<'a> VerifyEmailRequest<'a> {
+    pub fn new(verification_code: impl Into<CowStr<'a>>, email: impl Into<CowStr<'a>>) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            verification_code: verification_code.into(),
+            email: email.into(),
+        }
+    }
+}
+
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct ResendVerificationRequest<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] pub tag: TypeTag<2468159>,
+    #[b(1)] pub email: CowStr<'a>,
+}
+
+impl<'a> ResendVerificationRequest<'a> {
+    pub fn new(email: impl Into<CowStr<'a>>) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            email: email.into(),
+        }
+    }
+}
+
+#[derive(Encode, Decode, Serialize, Debug, Clone, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct VerificationStatus {
+    #[cfg(feature = "tag")]
+    #[serde(skip)]
+    #[n(0)] pub tag: TypeTag<9037742>,
+    #[n(1)] pub is_verified: bool,
+    /// Expiry of the current code in seconds since the Unix epoch
+    #[n(2)] pub expires_at: u64,
+    #[n(3)] pub verification_attempts: u32,
+}
+
+impl VerificationStatus {
+    /// Check if another code may be tried at `now` (seconds since the Unix epoch).
+    pub fn can_attempt_again(&self, now: u64) -> bool {
+        now < self.expires_at && self.verification_attempts < MAX_VERIFICATION_ATTEMPTS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(is_verified: bool, verification_attempts: u32) -> VerificationStatus {
+        VerificationStatus {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            is_verified,
+            expires_at: 2000,
+            verification_attempts,
+        }
+    }
+
+    #[test]
+    fn roundtrip() {
+        let s = status(true, 1);
+        let bytes = minicbor::to_vec(&s).unwrap();
+        let dec: VerificationStatus = minicbor::decode(&bytes).unwrap();
+        assert_eq!(s, dec);
+        assert!(dec.is_verified);
+
+        let req = VerifyEmailRequest::new("123456", "alice@example.com");
+        let bytes = minicbor::to_vec(&req).unwrap();
+        assert_eq!(req, minicbor::decode(&bytes).unwrap());
+
+        let req = ResendVerificationRequest::new("alice@example.com");
+        let bytes = minicbor::to_vec(&req).unwrap();
+        assert_eq!(req, minicbor::decode(&bytes).unwrap())
+    }
+
+    #[test]
+    fn can_attempt_again() {
+        assert!(status(false, 0).can_attempt_again(1999));
+        assert!(!status(false, 0).can_attempt_again(2000));
+        assert!(status(false, 4).can_attempt_again(1999));
+        assert!(!status(false, 5).can_attempt_again(1999));
+        assert!(!status(false, 5).can_attempt_again(2000))
+    }
+}
@@ -34,7 +34,7 @@ mod node {
 
     use crate::cloud::enroll::auth0::AuthenticateAuth0Token;
     use crate::cloud::enroll::enrollment_token::{
-        AuthenticateEnrollmentToken, EnrollmentToken, RequestEnrollmentToken,
+        AuthenticateEnrollmentToken, RedeemTokenRequest, RequestEnrollmentToken,
     };
     use crate::cloud::CloudRequestWrapper;
     use crate::nodes::NodeManagerWorker;
@@ -92,11 +92,12 @@ mod node {
             ctx: &mut Context,
             dec: &mut Decoder<'_>,
         ) -> Result<Vec<u8>> {
-            let req_wrapper: CloudRequestWrapper<EnrollmentToken> = dec.decode()?;
+            let req_wrapper: CloudRequestWrapper<RedeemTokenRequest> = dec.decode()?;
             let cloud_route = req_wrapper.route()?;
-            let req_body: EnrollmentToken = req_wrapper.req;
-            let req_body =
-                AuthenticateToken::EnrollmentToken(AuthenticateEnrollmentToken::new(req_body));
+            let req_body: RedeemTokenRequest = req_wrapper.req;
+            let req_body = AuthenticateToken::EnrollmentToken(AuthenticateEnrollmentToken::new(
+                Token::new(req_body.one_time_code),
+            ));
 
             trace!(target: TARGET, "authenticating token");
             self.authenticate_token(ctx, cloud_route, req_body).await
@@ -210,9 +211,12 @@ pub mod auth0 {
 }
 
 pub mod enrollment_token {
+    use ockam_core::CowStr;
     use ockam_identity::credential::Attributes;
     use serde::Serialize;
 
+    use crate::auth::types::AttributePair;
+
     use super::*;
 
     // Main req/res types
@@ -225,6 +229,8 @@ pub mod enrollment_token {
         #[cfg(feature = "tag")]
         #[n(0)] pub tag: TypeTag<8560526>,
         #[b(1)] pub attributes: Attributes<'a>,
+        /// How long the token stays valid, or the controller default if `None`
+        #[n(2)] pub ttl_secs: Option<u64>,
     }
 
     impl<'a> RequestEnrollmentToken<'a> {
@@ -233,8 +239,72 @@ pub mod enrollment_token {
                 #[cfg(feature = "tag")]
                 tag: TypeTag,
                 attributes,
+                ttl_secs: None,
             }
         }
+
+        pub fn ttl(mut self, secs: u64) -> Self {
+            self.ttl_secs = Some(secs);
+            self
+        }
+    }
+
+    /// Request body to create an enrollment token
+    #[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+    #[rustfmt::skip]
+    #[cbor(map)]
+    pub struct CreateTokenRequest<'a> {
+        #[cfg(feature = "tag")]
+        #[n(0)] pub tag: TypeTag<7264139>,
+        /// How long the token stays valid, or the controller default if `None`
+        #[n(1)] pub ttl_secs: Option<u64>,
+        #[b(2)] pub attrs: Vec<AttributePair<'a>>,
+    }
+
+    impl<'a> CreateTokenRequest<'a> {
+        pub fn new(attrs: Vec<AttributePair<'a>>) -> Self {
+            Self {
+                #[cfg(feature = "tag")]
+                tag: TypeTag,
+                ttl_secs: None,
+                attrs,
+            }
+        }
+
+        pub fn ttl(mut self, secs: u64) -> Self {
+            self.ttl_secs = Some(secs);
+            self
+        }
+    }
+
+    /// Request body to redeem an enrollment token
+    #[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+    #[rustfmt::skip]
+    #[cbor(map)]
+    pub struct RedeemTokenRequest<'a> {
+        #[cfg(feature = "tag")]
+        #[n(0)] pub tag: TypeTag<3097152>,
+        #[b(1)] pub one_time_code: CowStr<'a>,
+    }
+
+    impl<'a> RedeemTokenRequest<'a> {
+        pub fn new(one_time_code: impl Into<CowStr<'a>>) -> Self {
+            Self {
+                #[cfg(feature = "tag")]
+                tag: TypeTag,
+                one_time_code: one_time_code.into(),
+            }
+        }
+    }
+
+    /// The state of an enrollment token at some point in time
+    #[derive(Encode, Decode, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+    #[rustfmt::skip]
+    #[cbor(index_only)]
+    pub enum TokenStatus {
+        #[n(0)] Valid,
+        #[n(1)] Expired,
+        #[n(2)] Used,
     }
 
     #[derive(Encode, Decode, Serialize, Debug)]
@@ -245,15 +315,56 @@ pub mod enrollment_token {
         #[cfg(feature = "tag")]
         #[serde(skip)]
         #[n(0)] pub tag: TypeTag<8932763>,
+        /// The one-time code to redeem
         #[n(1)] pub token: Token<'a>,
+        #[b(2)] pub issued_by: CowStr<'a>,
+        /// Creation time in seconds since the Unix epoch
+        #[n(3)] pub created_at: u64,
+        /// Expiry time in seconds since the Unix epoch
+        #[n(4)] pub expires_at: u64,
+        #[n(5)] pub is_used: bool,
+        #[b(6)] pub attrs: Vec<AttributePair<'a>>,
     }
 
     impl<'a> EnrollmentToken<'a> {
-        pub fn new(token: Token<'a>) -> Self {
+        pub fn new(
+            token: Token<'a>,
+            issued_by: impl Into<CowStr<'a>>,
+            created_at: u64,
+            expires_at: u64,
+        ) -> Self {
             Self {
                 #[cfg(feature = "tag")]
                 tag: TypeTag,
                 token,
+                issued_by: issued_by.into(),
+                created_at,
+                expires_at,
+                is_used: false,
+                attrs: Vec::new(),
+            }
+        }
+
+        pub fn one_time_code(&self) -> &str {
+            &self.token.0
+        }
+
+        /// Check if the token has expired at `now` (seconds since the Unix epoch).
+        ///
+        /// The expiry time is a required field, so a token which lacks it
+        /// fails to decode and can not be checked at all.
+        pub fn is_expired(&self, now: u64) -> bool {
+            now >= self.expires_at
+        }
+
+        /// The status of this token at `now` (seconds since the Unix epoch).
+        pub fn status(&self, now: u64) -> TokenStatus {
+            if self.is_used {
+                TokenStatus::Used
+            } else if self.is_expired(now) {
+                TokenStatus::Expired
+            } else {
+                TokenStatus::Valid
             }
         }
     }
@@ -269,11 +380,11 @@ pub mod enrollment_token {
     }
 
     impl<'a> AuthenticateEnrollmentToken<'a> {
-        pub fn new(token: EnrollmentToken<'a>) -> Self {
+        pub fn new(token: Token<'a>) -> Self {
             Self {
                 #[cfg(feature = "tag")]
                 tag: TypeTag,
-                token: token.token,
+                token,
             }
         }
     }
@@ -284,7 +395,7 @@ pub mod enrollment_token {
 pub(crate) mod tests {
     use quickcheck::{Arbitrary, Gen};
 
-    use crate::cloud::enroll::enrollment_token::{AuthenticateEnrollmentToken, EnrollmentToken};
+    use crate::cloud::enroll::enrollment_token::AuthenticateEnrollmentToken;
     use crate::cloud::enroll::Token;
 
     use super::*;
@@ -320,6 +431,11 @@ pub(crate) mod tests {
     }
 
     mod enrollment_token {
+        use crate::auth::types::AttributePair;
+        use crate::cloud::enroll::enrollment_token::{
+            CreateTokenRequest, EnrollmentToken, RedeemTokenRequest, TokenStatus,
+        };
+
         use super::*;
 
         #[derive(Debug, Clone)]
@@ -327,9 +443,75 @@ pub(crate) mod tests {
 
         impl Arbitrary for RandomAuthorizedEnrollmentToken {
             fn arbitrary(g: &mut Gen) -> Self {
-                RandomAuthorizedEnrollmentToken(AuthenticateEnrollmentToken::new(
-                    EnrollmentToken::new(Token::arbitrary(g)),
-                ))
+                RandomAuthorizedEnrollmentToken(AuthenticateEnrollmentToken::new(Token::arbitrary(
+                    g,
+                )))
+            }
+        }
+
+        #[test]
+        fn is_expired() {
+            let token = EnrollmentToken::new(Token::new("otc"), "alice", 500, 1000);
+            assert!(!token.is_expired(0));
+            assert!(!token.is_expired(999));
+            assert!(token.is_expired(1000));
+            assert!(token.is_expired(1001));
+        }
+
+        #[test]
+        fn status() {
+            let mut token = EnrollmentToken::new(Token::new("otc"), "alice", 500, 1000);
+            assert_eq!(TokenStatus::Valid, token.status(999));
+            assert_eq!(TokenStatus::Expired, token.status(1000));
+            token.is_used = true;
+            assert_eq!(TokenStatus::Used, token.status(999));
+            assert_eq!(TokenStatus::Used, token.status(1000));
+        }
+
+        #[test]
+        fn missing_expiry_time() {
+            // A token without `expires_at` has no defined expiry and is
+            // rejected when decoding.
+            let mut e = minicbor::Encoder::new(Vec::new());
+            e.map(5).unwrap();
+            e.u8(1).unwrap().str("otc").unwrap();
+            e.u8(2).unwrap().str("alice").unwrap();
+            e.u8(3).unwrap().u64(500).unwrap();
+            e.u8(5).unwrap().bool(false).unwrap();
+            e.u8(6).unwrap().array(0).unwrap();
+            assert!(minicbor::decode::<EnrollmentToken>(e.writer()).is_err())
+        }
+
+        #[test]
+        fn roundtrip() {
+            let mut token = EnrollmentToken::new(Token::new("otc"), "alice", 1000, 2000);
+            token.attrs = vec![
+                AttributePair::new("role", "member"),
+                AttributePair::new("project", "p1"),
+            ];
+            let bytes = minicbor::to_vec(&token).unwrap();
+            let dec: EnrollmentToken = minicbor::decode(&bytes).unwrap();
+            assert_eq!(token.token, dec.token);
+            assert_eq!(token.issued_by, dec.issued_by);
+            assert_eq!(token.created_at, dec.created_at);
+            assert_eq!(token.expires_at, dec.expires_at);
+            assert_eq!(token.is_used, dec.is_used);
+            assert_eq!(token.attrs, dec.attrs);
+
+            let req = CreateTokenRequest::new(vec![AttributePair::new("role", "member")]);
+            let bytes = minicbor::to_vec(&req).unwrap();
+            assert_eq!(req, minicbor::decode(&bytes).unwrap());
+            let req = req.ttl(3600);
+            let bytes = minicbor::to_vec(&req).unwrap();
+            assert_eq!(req, minicbor::decode(&bytes).unwrap());
+
+            let req = RedeemTokenRequest::new("otc");
+            let bytes = minicbor::to_vec(&req).unwrap();
+            assert_eq!(req, minicbor::decode(&bytes).unwrap());
+
+            for status in [TokenStatus::Valid, TokenStatus::Expired, TokenStatus::Used] {
+                let bytes = minicbor::to_vec(status).unwrap();
+                assert_eq!(status, minicbor::decode(&bytes).unwrap());
             }
         }
     }
@@ -0,0 +1,123 @@
+use minicbor::{Decode, Encode};
+
+#[cfg(feature = "tag")]
+use ockam_core::TypeTag;
+use ockam_core::{CowBytes, CowStr};
+
+/// The controller path to create, get, verify and delete identities
+pub fn identity_path() -> &'static str {
+    "/v0/identity"
+}
+
+/// Register a new identity, which is generated by the cloud.
+#[derive(Encode, Decode, Debug, Clone, Default, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct CreateIdentityRequest {
+    #[cfg(feature = "tag")]
+    #[n(0)] pub tag: TypeTag<6091372>,
+}
+
+impl CreateIdentityRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct GetIdentityResponse<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] pub tag: TypeTag<4187530>,
+    #[b(1)] pub identifier: CowStr<'a>,
+    /// The exported change history of the identity
+    #[b(2)] pub change_history: CowBytes<'a>,
+}
+
+impl<'a> GetIdentityResponse<'a> {
+    pub fn new(identifier: impl Into<CowStr<'a>>, change_history: impl Into<CowBytes<'a>>) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            identifier: identifier.into(),
+            change_history: change_history.into(),
+        }
+    }
+}
+
+/// Ask the cloud to verify the change history of an identity.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct VerifyIdentityRequest<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] pub tag: TypeTag<2935814>,
+    #[b(1)] pub identifier: CowStr<'a>,
+    #[b(2)] pub change_history: CowBytes<'a>,
+}
+
+impl<'a> VerifyIdentityRequest<'a> {
+    pub fn new(identifier: impl Into<CowStr<'a>>, change_history: impl Into<CowBytes<'a>>) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            identifier: identifier.into(),
+            change_history: change_history.into(),
+        }
+    }
+}
+
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct DeleteIdentityRequest<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] pub tag: TypeTag<8402617>,
+    #[b(1)] pub identifier: CowStr<'a>,
+}
+
+impl<'a> DeleteIdentityRequest<'a> {
+    pub fn new(identifier: impl Into<CowStr<'a>>) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            identifier: identifier.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ID: &str = "P6474cfdbf547240b6d716bff89c976810859bc3f47be8ea620df12a392ea6cb7";
+
+    #[test]
+    fn large_change_history() {
+        let history: Vec<u8> = (0..1000).map(|i| i as u8).collect();
+        let res = GetIdentityResponse::new(ID, &history[..]);
+        let bytes = minicbor::to_vec(&res).unwrap();
+        let dec: GetIdentityResponse = minicbor::decode(&bytes).unwrap();
+        assert_eq!(res, dec);
+        assert_eq!(1000, dec.change_history.len());
+        assert_eq!(&history[..], &*dec.change_history);
+        assert!(dec.change_history.is_borrowed());
+        assert!(dec.identifier.is_borrowed())
+    }
+
+    #[test]
+    fn roundtrip() {
+        let req = CreateIdentityRequest::new();
+        let bytes = minicbor::to_vec(&req).unwrap();
+        assert_eq!(req, minicbor::decode(&bytes).unwrap());
+
+        let req = VerifyIdentityRequest::new(ID, &b"history"[..]);
+        let bytes = minicbor::to_vec(&req).unwrap();
+        assert_eq!(req, minicbor::decode(&bytes).unwrap());
+
+        let req = DeleteIdentityRequest::new(ID);
+        let bytes = minicbor::to_vec(&req).unwrap();
+        assert_eq!(req, minicbor::decode(&bytes).unwrap());
+    }
+}
@@ -0,0 +1,167 @@
+use minicbor::{Decode, Encode};
+
+use ockam_core::CowStr;
+#[cfg(feature = "tag")]
+use ockam_core::TypeTag;
+
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct InfluxDBConfig<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] pub tag: TypeTag<7265019>,
+    #[b(1)] pub endpoint: CowStr<'a>,
+    #[b(2)] pub org_id: CowStr<'a>,
+    /// The JSON permissions document of leased tokens
+    #[b(3)] pub permissions: CowStr<'a>,
+    #[n(4)] pub max_ttl_secs: u32,
+    /// The ID of the admin token stored in the cloud
+    #[b(5)] pub token_id: CowStr<'a>,
+}
+
+impl<'a> InfluxDBConfig<'a> {
+    pub fn new(
+        endpoint: impl Into<CowStr<'a>>,
+        org_id: impl Into<CowStr<'a>>,
+        permissions: impl Into<CowStr<'a>>,
+        max_ttl_secs: u32,
+        token_id: impl Into<CowStr<'a>>,
+    ) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            endpoint: endpoint.into(),
+            org_id: org_id.into(),
+            permissions: permissions.into(),
+            max_ttl_secs,
+            token_id: token_id.into(),
+        }
+    }
+}
+
+#[derive(Encode, Decode, Debug, Clone, Copy, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(index_only)]
+pub enum LeaseStatus {
+    #[n(0)] Active,
+    #[n(1)] Revoked,
+}
+
+/// A short-lived InfluxDB token.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct TokenLease<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] pub tag: TypeTag<1843706>,
+    #[b(1)] pub id: CowStr<'a>,
+    #[b(2)] pub token: CowStr<'a>,
+    /// Seconds since the Unix epoch
+    #[n(3)] pub created_at: u64,
+    /// Seconds since the Unix epoch
+    #[n(4)] pub expires_at: u64,
+    #[n(5)] pub status: LeaseStatus,
+}
+
+impl<'a> TokenLease<'a> {
+    /// Check if the lease has expired at `now` (seconds since the Unix epoch).
+    pub fn is_expired(&self, now: u64) -> bool {
+        now >= self.expires_at
+    }
+
+    /// A lease can be used if it has neither expired nor been revoked.
+    pub fn is_usable(&self, now: u64) -> bool {
+        self.status == LeaseStatus::Active && !self.is_expired(now)
+    }
+}
+
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct CreateTokenLeaseRequest<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] pub tag: TypeTag<5530948>,
+    #[n(1)] pub ttl_secs: u32,
+    /// Overrides the permissions of the addon configuration
+    #[b(2)] pub permissions: Option<CowStr<'a>>,
+}
+
+impl<'a> CreateTokenLeaseRequest<'a> {
+    pub fn new(ttl_secs: u32, permissions: Option<impl Into<CowStr<'a>>>) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            ttl_secs,
+            permissions: permissions.map(|p| p.into()),
+        }
+    }
+}
+
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct RevokeTokenLeaseRequest<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] pub tag: TypeTag<3962285>,
+    #[b(1)] pub lease_id: CowStr<'a>,
+}
+
+impl<'a> RevokeTokenLeaseRequest<'a> {
+    pub fn new(lease_id: impl Into<CowStr<'a>>) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            lease_id: lease_id.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lease(status: LeaseStatus) -> TokenLease<'static> {
+        TokenLease {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            id: "l1".into(),
+            token: "secret".into(),
+            created_at: 1000,
+            expires_at: 2000,
+            status,
+        }
+    }
+
+    #[test]
+    fn lease_expiry() {
+        let l = lease(LeaseStatus::Active);
+        assert!(!l.is_expired(1999));
+        assert!(l.is_expired(2000));
+        assert!(l.is_usable(1999));
+        assert!(!l.is_usable(2000));
+        assert!(!lease(LeaseStatus::Revoked).is_usable(1999));
+    }
+
+    #[test]
+    fn roundtrip() {
+        let l = lease(LeaseStatus::Revoked);
+        let bytes = minicbor::to_vec(&l).unwrap();
+        assert_eq!(l, minicbor::decode(&bytes).unwrap());
+
+        let c = InfluxDBConfig::new("https://influx.example", "o1", "[]", 3600, "t1");
+        let bytes = minicbor::to_vec(&c).unwrap();
+        assert_eq!(c, minicbor::decode(&bytes).unwrap());
+
+        for req in [
+            CreateTokenLeaseRequest::new(600, None::<&str>),
+            CreateTokenLeaseRequest::new(600, Some(r#"[{"action":"read"}]"#)),
+        ] {
+            let bytes = minicbor::to_vec(&req).unwrap();
+            assert_eq!(req, minicbor::decode(&bytes).unwrap());
+        }
+
+        let req = RevokeTokenLeaseRequest::new("l1");
+        let bytes = minicbor::to_vec(&req).unwrap();
+        assert_eq!(req, minicbor::decode(&bytes).unwrap());
+    }
+}
@@ -5,6 +5,8 @@ use ockam_core::CowStr;
 #[cfg(feature = "tag")]
 use ockam_core::TypeTag;
 
+use crate::pagination::PaginatedResponse;
+
 #[derive(Encode, Decode, Serialize, Debug)]
 #[cfg_attr(test, derive(Clone))]
 #[rustfmt::skip]
@@ -68,27 +70,116 @@ impl<'a> CreateInvitation<'a> {
     }
 }
 
+/// The controller path of invitations
+pub fn invitations_path() -> &'static str {
+    "/v0/invitations"
+}
+
+/// What an invitation grants access to.
+#[derive(Encode, Decode, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(index_only)]
+pub enum InvitationScope {
+    #[n(0)] Space,
+    #[n(1)] Project,
+}
+
+#[derive(Encode, Decode, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(index_only)]
+pub enum InvitationStatus {
+    #[n(0)] Pending,
+    #[n(1)] Accepted,
+    #[n(2)] Expired,
+}
+
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct CreateInvitationRequest<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] pub tag: TypeTag<3306718>,
+    #[b(1)] pub invitee_email: CowStr<'a>,
+    #[n(2)] pub scope: InvitationScope,
+    /// The ID of the space or project, depending on `scope`
+    #[b(3)] pub target_id: CowStr<'a>,
+}
+
+impl<'a> CreateInvitationRequest<'a> {
+    pub fn new(
+        invitee_email: impl Into<CowStr<'a>>,
+        scope: InvitationScope,
+        target_id: impl Into<CowStr<'a>>,
+    ) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            invitee_email: invitee_email.into(),
+            scope,
+            target_id: target_id.into(),
+        }
+    }
+}
+
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct AcceptInvitationRequest<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] pub tag: TypeTag<7542091>,
+    #[b(1)] pub invitation_id: CowStr<'a>,
+}
+
+impl<'a> AcceptInvitationRequest<'a> {
+    pub fn new(invitation_id: impl Into<CowStr<'a>>) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            invitation_id: invitation_id.into(),
+        }
+    }
+}
+
+#[derive(Encode, Decode, Serialize, Debug, Clone, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct InvitationInfo<'a> {
+    #[cfg(feature = "tag")]
+    #[serde(skip)]
+    #[n(0)] pub tag: TypeTag<1189460>,
+    #[b(1)] pub id: CowStr<'a>,
+    /// The email address of the inviting user
+    #[b(2)] pub inviter: CowStr<'a>,
+    /// The email address of the invited user
+    #[b(3)] pub invitee: CowStr<'a>,
+    #[n(4)] pub scope: InvitationScope,
+    #[b(5)] pub target_id: CowStr<'a>,
+    /// Seconds since the Unix epoch
+    #[n(6)] pub created_at: u64,
+    #[n(7)] pub status: InvitationStatus,
+}
+
+pub type ListInvitationsResponse<'a> = PaginatedResponse<'a, InvitationInfo<'a>>;
+
 mod node {
     use minicbor::Decoder;
     use tracing::trace;
 
+    use ockam_core::api::Request;
     use ockam_core::{self, Result};
     use ockam_node::Context;
 
     use crate::cloud::{BareCloudRequestWrapper, CloudRequestWrapper};
-    use crate::nodes::NodeManager;
-    use crate::request;
-    use crate::{Request, Response, Status};
+    use crate::nodes::NodeManagerWorker;
 
     use super::*;
 
     const TARGET: &str = "ockam_api::cloud::invitation";
 
-    impl NodeManager {
+    impl NodeManagerWorker {
         pub(crate) async fn create_invitation(
             &mut self,
             ctx: &mut Context,
-            req: &Request<'_>,
             dec: &mut Decoder<'_>,
         ) -> Result<Vec<u8>> {
             let req_wrapper: CloudRequestWrapper<CreateInvitation> = dec.decode()?;
@@ -104,27 +195,21 @@ mod node {
                 "creating invitation"
             };
 
-            let sc = self.secure_channel(cloud_route).await?;
-            let route = self.cloud_service_route(&sc.to_string(), "invitations");
-
-            let req_builder = Request::post("v0/").body(req_body);
-            let res = match request(ctx, label, "create_invitation", route, req_builder).await {
-                Ok(r) => Ok(r),
-                Err(err) => {
-                    error!(?err, "Failed to create invitation");
-                    Ok(Response::builder(req.id(), Status::InternalServerError)
-                        .body(err.to_string())
-                        .to_vec()?)
-                }
-            };
-            self.delete_secure_channel(ctx, sc).await?;
-            res
+            let req_builder = Request::post("/v0/").body(req_body);
+            self.request_controller(
+                ctx,
+                label,
+                "create_invitation",
+                cloud_route,
+                "invitations",
+                req_builder,
+            )
+            .await
         }
 
         pub(crate) async fn list_invitations(
             &mut self,
             ctx: &mut Context,
-            req: &Request<'_>,
             dec: &mut Decoder<'_>,
         ) -> Result<Vec<u8>> {
             let req_wrapper: BareCloudRequestWrapper = dec.decode()?;
@@ -133,27 +218,14 @@ mod node {
             let label = "list_invitations";
             trace!(target: TARGET, "listing invitations");
 
-            let sc = self.secure_channel(cloud_route).await?;
-            let route = self.cloud_service_route(&sc.to_string(), "invitations");
-
-            let req_builder = Request::get("v0/");
-            let res = match request(ctx, label, None, route, req_builder).await {
-                Ok(r) => Ok(r),
-                Err(err) => {
-                    error!(?err, "Failed to retrieve invitations");
-                    Ok(Response::builder(req.id(), Status::InternalServerError)
-                        .body(err.to_string())
-                        .to_vec()?)
-                }
-            };
-            self.delete_secure_channel(ctx, sc).await?;
-            res
+            let req_builder = Request::get("/v0/");
+            self.request_controller(ctx, label, None, cloud_route, "invitations", req_builder)
+                .await
         }
 
         pub(crate) async fn accept_invitation(
             &mut self,
             ctx: &mut Context,
-            req: &Request<'_>,
             dec: &mut Decoder<'_>,
             id: &str,
         ) -> Result<Vec<u8>> {
@@ -163,27 +235,14 @@ mod node {
             let label = "accept_invitation";
             trace!(target: TARGET, %id, "accepting invitation");
 
-            let sc = self.secure_channel(cloud_route).await?;
-            let route = self.cloud_service_route(&sc.to_string(), "invitations");
-
-            let req_builder = Request::put(format!("v0/{id}"));
-            let res = match request(ctx, label, None, route, req_builder).await {
-                Ok(r) => Ok(r),
-                Err(err) => {
-                    error!(?err, "Failed to accept invitation");
-                    Ok(Response::builder(req.id(), Status::InternalServerError)
-                        .body(err.to_string())
-                        .to_vec()?)
-                }
-            };
-            self.delete_secure_channel(ctx, sc).await?;
-            res
+            let req_builder = Request::put(format!("/v0/{id}"));
+            self.request_controller(ctx, label, None, cloud_route, "invitations", req_builder)
+                .await
         }
 
         pub(crate) async fn reject_invitation(
             &mut self,
             ctx: &mut Context,
-            req: &Request<'_>,
             dec: &mut Decoder<'_>,
             id: &str,
         ) -> Result<Vec<u8>> {
@@ -193,43 +252,24 @@ mod node {
             let label = "reject_invitation";
             trace!(target: TARGET, %id, "rejecting invitation");
 
-            let sc = self.secure_channel(cloud_route).await?;
-            let route = self.cloud_service_route(&sc.to_string(), "invitations");
-
-            let req_builder = Request::delete(format!("v0/{id}"));
-            let res = match request(ctx, label, None, route, req_builder).await {
-                Ok(r) => Ok(r),
-                Err(err) => {
-                    error!(?err, "Failed to reject invitation");
-                    Ok(Response::builder(req.id(), Status::InternalServerError)
-                        .body(err.to_string())
-                        .to_vec()?)
-                }
-            };
-            self.delete_secure_channel(ctx, sc).await?;
-            res
+            let req_builder = Request::delete(format!("/v0/{id}"));
+            self.request_controller(ctx, label, None, cloud_route, "invitations", req_builder)
+                .await
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use minicbor::Decoder;
     use quickcheck::{Arbitrary, Gen};
 
-    use ockam_core::compat::collections::HashMap;
-    use ockam_core::{Routed, Worker};
-    use ockam_node::Context;
-
-    use crate::{Method, Request, Response};
-
     use super::*;
 
     mod schema {
         use cddl_cat::validate_cbor_bytes;
         use quickcheck::{quickcheck, TestResult};
 
-        use crate::SCHEMA;
+        use ockam_core::api::SCHEMA;
 
         use super::*;
 
@@ -321,270 +361,61 @@ mod tests {
         }
     }
 
-    mod node_api {
-        use crate::cloud::CloudRequestWrapper;
-        use crate::nodes::NodeManager;
-        use crate::{route_to_multiaddr, Status};
-        use ockam_core::route;
-
-        use super::*;
-
-        #[ockam_macros::test]
-        async fn accept(ctx: &mut Context) -> ockam_core::Result<()> {
-            // Create node manager to handle requests
-            let route =
-                NodeManager::test_create(ctx, "invitations", InvitationServer::default()).await?;
-            let cloud_route = route_to_multiaddr(&route!["cloud"]).unwrap();
-
-            // Create invitation
-            let req = CreateInvitation::new("invitee", "s1", Some("p1"));
-            let mut buf = vec![];
-            Request::post("v0/invitations")
-                .body(CloudRequestWrapper::new(req.clone(), &cloud_route))
-                .encode(&mut buf)?;
-            let response: Vec<u8> = ctx.send_and_receive(route.clone(), buf).await?;
-            let mut dec = Decoder::new(&response);
-            let header = dec.decode::<Response>()?;
-            assert_eq!(header.status, Some(Status::Ok));
-            let i = dec.decode::<Invitation>()?;
-            // TODO: the inviter must be the `client_identity` created, as that's the identity invoking
-            //       the API. But the simple test server here doesn't retrieve the peer identity from the
-            //       secure channel metadata.
-            //assert_eq!(&i.inviter, client_identity.id);
-            assert_eq!(&i.invitee, &req.invitee);
-            assert_eq!(&i.space_id, &req.space_id);
-            assert_eq!(&i.project_id, &req.project_id);
-            assert_eq!(&i.state, &State::Pending);
-            assert_eq!(&i.scope, &req.scope);
-            let i_id = i.id.to_string();
-
-            // List it
-            let mut buf = vec![];
-            Request::get("v0/invitations")
-                .body(CloudRequestWrapper::bare(&cloud_route))
-                .encode(&mut buf)?;
-            let response: Vec<u8> = ctx.send_and_receive(route.clone(), buf).await?;
-            let mut dec = Decoder::new(&response);
-            let header = dec.decode::<Response>()?;
-            assert_eq!(header.status, Some(Status::Ok));
-            let list = dec.decode::<Vec<Invitation>>()?;
-            assert_eq!(list.len(), 1);
-            assert_eq!(&list[0].id.to_string(), &i_id);
-
-            // Accept invitation
-            let mut buf = vec![];
-            Request::put(format!("v0/invitations/{i_id}"))
-                .body(CloudRequestWrapper::bare(&cloud_route))
-                .encode(&mut buf)?;
-            let response: Vec<u8> = ctx.send_and_receive(route.clone(), buf).await?;
-            let mut dec = Decoder::new(&response);
-            let header = dec.decode::<Response>()?;
-            assert_eq!(header.status, Some(Status::Ok));
-
-            // Check that status has changed
-            let mut buf = vec![];
-            Request::get("v0/invitations")
-                .body(CloudRequestWrapper::bare(&cloud_route))
-                .encode(&mut buf)?;
-            let response: Vec<u8> = ctx.send_and_receive(route.clone(), buf).await?;
-            let mut dec = Decoder::new(&response);
-            let header = dec.decode::<Response>()?;
-            assert_eq!(header.status, Some(Status::Ok));
-            let list = dec.decode::<Vec<Invitation>>()?;
-            assert_eq!(list.len(), 1);
-            assert_eq!(&list[0].id.to_string(), &i_id);
-            assert_eq!(&list[0].state, &State::Accepted);
-
-            // Rejecting an accepted invitation should fail
-            let mut buf = vec![];
-            Request::delete(format!("v0/invitations/{i_id}"))
-                .body(CloudRequestWrapper::bare(&cloud_route))
-                .encode(&mut buf)?;
-            let response: Vec<u8> = ctx.send_and_receive(route.clone(), buf).await?;
-            let mut dec = Decoder::new(&response);
-            let header = dec.decode::<Response>()?;
-            assert_eq!(header.status, Some(Status::BadRequest));
-
-            ctx.stop().await
-        }
-
-        #[ockam_macros::test]
-        async fn reject(ctx: &mut Context) -> ockam_core::Result<()> {
-            // Create node manager to handle requests
-            let route =
-                NodeManager::test_create(ctx, "invitations", InvitationServer::default()).await?;
-            let cloud_route = route_to_multiaddr(&route!["cloud"]).unwrap();
-
-            // Create invitation
-            let req = CreateInvitation::new("invitee", "s1", Some("p1"));
-            let mut buf = vec![];
-            Request::post("v0/invitations")
-                .body(CloudRequestWrapper::new(req.clone(), &cloud_route))
-                .encode(&mut buf)?;
-            let response: Vec<u8> = ctx.send_and_receive(route.clone(), buf).await?;
-            let mut dec = Decoder::new(&response);
-            let header = dec.decode::<Response>()?;
-            assert_eq!(header.status, Some(Status::Ok));
-            let i = dec.decode::<Invitation>()?;
-            // TODO: the inviter must be the `client_identity` created, as that's the identity invoking
-            //       the API. But the simple test server here doesn't retrieve the peer identity from the
-            //       secure channel metadata.
-            //assert_eq!(&i.inviter, client_identity.id);
-            assert_eq!(&i.invitee, &req.invitee);
-            assert_eq!(&i.space_id, &req.space_id);
-            assert_eq!(&i.project_id, &req.project_id);
-            assert_eq!(&i.state, &State::Pending);
-            assert_eq!(&i.scope, &req.scope);
-            let i_id = i.id.to_string();
-
-            // List it
-            let mut buf = vec![];
-            Request::get("v0/invitations")
-                .body(CloudRequestWrapper::bare(&cloud_route))
-                .encode(&mut buf)?;
-            let response: Vec<u8> = ctx.send_and_receive(route.clone(), buf).await?;
-            let mut dec = Decoder::new(&response);
-            let header = dec.decode::<Response>()?;
-            assert_eq!(header.status, Some(Status::Ok));
-            let list = dec.decode::<Vec<Invitation>>()?;
-            assert_eq!(list.len(), 1);
-            assert_eq!(&list[0].id.to_string(), &i_id);
-
-            // Reject invitation
-            let mut buf = vec![];
-            Request::delete(format!("v0/invitations/{i_id}"))
-                .body(CloudRequestWrapper::bare(&cloud_route))
-                .encode(&mut buf)?;
-            let response: Vec<u8> = ctx.send_and_receive(route.clone(), buf).await?;
-            let mut dec = Decoder::new(&response);
-            let header = dec.decode::<Response>()?;
-            assert_eq!(header.status, Some(Status::Ok));
-
-            // Check that status has changed
-            let mut buf = vec![];
-            Request::get("v0/invitations")
-                .body(CloudRequestWrapper::bare(&cloud_route))
-                .encode(&mut buf)?;
-            let response: Vec<u8> = ctx.send_and_receive(route.clone(), buf).await?;
-            let mut dec = Decoder::new(&response);
-            let header = dec.decode::<Response>()?;
-            assert_eq!(header.status, Some(Status::Ok));
-            let list = dec.decode::<Vec<Invitation>>()?;
-            assert_eq!(list.len(), 1);
-            assert_eq!(&list[0].id.to_string(), &i_id);
-            assert_eq!(&list[0].state, &State::Rejected);
-
-            // Accepting a rejected invitation should fail
-            let mut buf = vec![];
-            Request::put(format!("v0/invitations/{i_id}"))
-                .body(CloudRequestWrapper::bare(&cloud_route))
-                .encode(&mut buf)?;
-            let response: Vec<u8> = ctx.send_and_receive(route.clone(), buf).await?;
-            let mut dec = Decoder::new(&response);
-            let header = dec.decode::<Response>()?;
-            assert_eq!(header.status, Some(Status::BadRequest));
-
-            ctx.stop().await
+    fn invitation(id: &'static str, status: InvitationStatus) -> InvitationInfo<'static> {
+        InvitationInfo {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            id: id.into(),
+            inviter: "alice@example.com".into(),
+            invitee: "bob@example.com".into(),
+            scope: InvitationScope::Project,
+            target_id: "p1".into(),
+            created_at: 1000,
+            status,
         }
     }
 
-    #[derive(Debug, Default)]
-    pub struct InvitationServer {
-        by_id: HashMap<String, Invitation<'static>>,
-    }
-
-    #[ockam_core::worker]
-    impl Worker for InvitationServer {
-        type Message = Vec<u8>;
-        type Context = Context;
-
-        async fn handle_message(
-            &mut self,
-            ctx: &mut Context,
-            msg: Routed<Self::Message>,
-        ) -> ockam_core::Result<()> {
-            let r = self.on_request(msg.as_body())?;
-            ctx.send(msg.return_route(), r).await
+    #[test]
+    fn enums() {
+        for scope in [InvitationScope::Space, InvitationScope::Project] {
+            let bytes = minicbor::to_vec(scope).unwrap();
+            assert_eq!(scope, minicbor::decode(&bytes).unwrap());
+            let req = CreateInvitationRequest::new("bob@example.com", scope, "t1");
+            let bytes = minicbor::to_vec(&req).unwrap();
+            assert_eq!(req, minicbor::decode(&bytes).unwrap());
+        }
+        for status in [
+            InvitationStatus::Pending,
+            InvitationStatus::Accepted,
+            InvitationStatus::Expired,
+        ] {
+            let bytes = minicbor::to_vec(status).unwrap();
+            assert_eq!(status, minicbor::decode(&bytes).unwrap());
         }
     }
 
-    impl InvitationServer {
-        fn on_request(&mut self, data: &[u8]) -> ockam_core::Result<Vec<u8>> {
-            let mut rng = Gen::new(32);
-            let mut dec = Decoder::new(data);
-            let req: Request = dec.decode()?;
-            let r = match req.method() {
-                Some(Method::Post) if req.has_body() => {
-                    if let Ok(invitation) = dec.decode::<CreateInvitation>() {
-                        let obj = Invitation {
-                            #[cfg(feature = "tag")]
-                            tag: TypeTag,
-                            id: u32::arbitrary(&mut rng).to_string().into(),
-                            inviter: "inviter-id".into(),
-                            invitee: invitation.invitee.to_string().into(),
-                            scope: invitation.scope.clone(),
-                            state: State::Pending,
-                            space_id: invitation.space_id.to_string().into(),
-                            project_id: invitation.project_id.map(|s| s.to_string().into()),
-                        };
-                        let id = obj.id.to_string();
-                        self.by_id.insert(id, obj.clone());
-                        Response::ok(req.id()).body(&obj).to_vec()?
-                    } else {
-                        error!("Invalid request: {req:#?}");
-                        Response::bad_request(req.id()).to_vec()?
-                    }
-                }
-                Some(Method::Get) => {
-                    let invitations = self.by_id.values().collect::<Vec<_>>();
-                    Response::ok(req.id()).body(invitations).to_vec()?
-                }
-                Some(Method::Put) => match req.path_segments::<2>().as_slice() {
-                    // Accept invitation:
-                    [_, id] => {
-                        if let Some(invitation) = self.by_id.get_mut(*id) {
-                            if invitation.state == State::Pending {
-                                invitation.state = State::Accepted;
-                                Response::ok(req.id()).to_vec()?
-                            } else {
-                                Response::bad_request(req.id()).to_vec()?
-                            }
-                        } else {
-                            error!("Invalid request: {req:#?}");
-                            Response::not_found(req.id()).to_vec()?
-                        }
-                    }
-                    _ => {
-                        error!("Invalid request: {req:#?}");
-                        Response::bad_request(req.id()).to_vec()?
-                    }
-                },
-                Some(Method::Delete) => match req.path_segments::<2>().as_slice() {
-                    // Reject invitation:
-                    [_, id] => {
-                        if let Some(invitation) = self.by_id.get_mut(*id) {
-                            if invitation.state == State::Pending {
-                                invitation.state = State::Rejected;
-                                Response::ok(req.id()).to_vec()?
-                            } else {
-                                Response::bad_request(req.id()).to_vec()?
-                            }
-                        } else {
-                            error!("Invalid request: {req:#?}");
-                            Response::not_found(req.id()).to_vec()?
-                        }
-                    }
-                    _ => {
-                        error!("Invalid request: {req:#?}");
-                        Response::bad_request(req.id()).to_vec()?
-                    }
-                },
-                _ => {
-                    error!("Invalid request: {req:#?}");
-                    Response::bad_request(req.id()).to_vec()?
-                }
-            };
-            Ok(r)
-        }
+    #[test]
+    fn list_invitations_response() {
+        let res = ListInvitationsResponse::complete(vec![
+            invitation("i1", InvitationStatus::Pending),
+            invitation("i2", InvitationStatus::Accepted),
+            invitation("i3", InvitationStatus::Expired),
+        ]);
+        let bytes = minicbor::to_vec(&res).unwrap();
+        let dec: ListInvitationsResponse = minicbor::decode(&bytes).unwrap();
+        assert_eq!(res, dec);
+        let statuses: Vec<_> = dec.items.iter().map(|i| i.status).collect();
+        assert_eq!(
+            vec![
+                InvitationStatus::Pending,
+                InvitationStatus::Accepted,
+                InvitationStatus::Expired
+            ],
+            statuses
+        );
+
+        let req = AcceptInvitationRequest::new("i1");
+        let bytes = minicbor::to_vec(&req).unwrap();
+        assert_eq!(req, minicbor::decode(&bytes).unwrap())
     }
 }
@@ -0,0 +1,150 @@
+use minicbor::{Decode, Encode};
+use serde::Serialize;
+
+use ockam_core::CowStr;
+#[cfg(feature = "tag")]
+use ockam_core::TypeTag;
+
+use crate::auth::types::AttributePair;
+
+/// The controller path of the members of a project
+pub fn members_path(space_id: &str, project_id: &str) -> String {
+    format!("/v0/spaces/{space_id}/projects/{project_id}/members")
+}
+
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct AddMemberRequest<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] pub tag: TypeTag<4463190>,
+    /// The identifier of the ockam identity to add
+    #[b(1)] pub identifier: CowStr<'a>,
+    #[b(2)] pub attributes: Vec<AttributePair<'a>>,
+}
+
+impl<'a> AddMemberRequest<'a> {
+    pub fn new(identifier: impl Into<CowStr<'a>>) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            identifier: identifier.into(),
+            attributes: Vec::new(),
+        }
+    }
+
+    /// Set an attribute, replacing any previous value of the same key.
+    pub fn set_attribute(
+        mut self,
+        key: impl Into<CowStr<'a>>,
+        value: impl Into<CowStr<'a>>,
+    ) -> Self {
+        let key = key.into();
+        self.attributes.retain(|a| a.key != *key);
+        self.attributes.push(AttributePair::new(key, value));
+        self
+    }
+}
+
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct RemoveMemberRequest<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] pub tag: TypeTag<9315027>,
+    #[b(1)] pub identifier: CowStr<'a>,
+}
+
+impl<'a> RemoveMemberRequest<'a> {
+    pub fn new(identifier: impl Into<CowStr<'a>>) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            identifier: identifier.into(),
+        }
+    }
+}
+
+#[derive(Encode, Decode, Serialize, Debug, Clone, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct MemberInfo<'a> {
+    #[cfg(feature = "tag")]
+    #[serde(skip)]
+    #[n(0)] pub tag: TypeTag<1748026>,
+    #[b(1)] pub identifier: CowStr<'a>,
+    #[b(2)] pub attributes: Vec<AttributePair<'a>>,
+    /// Time the member was added, in seconds since the Unix epoch
+    #[n(3)] pub added_at: u64,
+}
+
+#[derive(Encode, Decode, Serialize, Debug, Clone, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct ListMembersResponse<'a> {
+    #[cfg(feature = "tag")]
+    #[serde(skip)]
+    #[n(0)] pub tag: TypeTag<5902643>,
+    #[b(1)] pub members: Vec<MemberInfo<'a>>,
+    /// Total number of members, which may exceed the length of `members`
+    #[n(2)] pub total: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_attribute() {
+        let req = AddMemberRequest::new("P1234")
+            .set_attribute("role", "admin")
+            .set_attribute("team", "ops")
+            .set_attribute("role", "member");
+        assert_eq!(
+            req.attributes,
+            vec![
+                AttributePair::new("team", "ops"),
+                AttributePair::new("role", "member")
+            ]
+        );
+    }
+
+    #[test]
+    fn add_member_roundtrip() {
+        let req = AddMemberRequest::new("P1234")
+            .set_attribute("role", "admin")
+            .set_attribute("team", "ops");
+        let bytes = minicbor::to_vec(&req).unwrap();
+        assert_eq!(req, minicbor::decode(&bytes).unwrap());
+    }
+
+    #[test]
+    fn list_members_roundtrip() {
+        let member = |id: &'static str, added_at| MemberInfo {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            identifier: id.into(),
+            attributes: vec![
+                AttributePair::new("role", "member"),
+                AttributePair::new("team", "ops"),
+            ],
+            added_at,
+        };
+        let res = ListMembersResponse {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            members: vec![member("P1", 1000), member("P2", 2000)],
+            total: 5,
+        };
+        let bytes = minicbor::to_vec(&res).unwrap();
+        assert_eq!(res, minicbor::decode(&bytes).unwrap());
+    }
+
+    #[test]
+    fn path() {
+        assert_eq!(
+            "/v0/spaces/s1/projects/p1/members",
+            members_path("s1", "p1")
+        );
+    }
+}
@@ -9,7 +9,15 @@ use ockam_multiaddr::MultiAddr;
 
 use crate::error::ApiError;
 
+pub mod addon;
+pub mod confluent;
+pub mod email_verification;
 pub mod enroll;
+pub mod identity;
+pub mod influxdb;
+pub mod invitation;
+pub mod member;
+pub mod okta;
 pub mod project;
 pub mod space;
 pub mod subscription;
@@ -0,0 +1,141 @@
+use minicbor::{Decode, Encode};
+
+use ockam_core::CowStr;
+#[cfg(feature = "tag")]
+use ockam_core::TypeTag;
+
+use crate::auth::types::AttributePair;
+use crate::cloud::addon::ConfigError;
+
+/// Path of the endpoint which adds the Okta addon to a project.
+pub fn okta_addon_path(space_id: &str, project_id: &str) -> String {
+    format!("/v0/spaces/{space_id}/projects/{project_id}/addons/okta")
+}
+
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct OktaConfig<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] pub tag: TypeTag<2458372>,
+    #[b(1)] pub tenant_base_url: CowStr<'a>,
+    /// The PEM encoded certificate of the tenant
+    #[b(2)] pub certificate: CowStr<'a>,
+    #[b(3)] pub client_id: CowStr<'a>,
+    /// How Okta user attributes map to Ockam attributes
+    #[b(4)] pub attribute_mappings: Vec<AttributePair<'a>>,
+}
+
+impl<'a> OktaConfig<'a> {
+    pub fn new(
+        tenant_base_url: impl Into<CowStr<'a>>,
+        certificate: impl Into<CowStr<'a>>,
+        client_id: impl Into<CowStr<'a>>,
+    ) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            tenant_base_url: tenant_base_url.into(),
+            certificate: certificate.into(),
+            client_id: client_id.into(),
+            attribute_mappings: Vec::new(),
+        }
+    }
+
+    pub fn with_attribute_mapping(
+        mut self,
+        okta: impl Into<CowStr<'a>>,
+        ockam: impl Into<CowStr<'a>>,
+    ) -> Self {
+        self.attribute_mappings
+            .push(AttributePair::new(okta, ockam));
+        self
+    }
+
+    /// Check that the tenant is reached via HTTPS and a certificate is given.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if !self.tenant_base_url.starts_with("https://") {
+            return Err(ConfigError::InsecureTenantUrl);
+        }
+        if self.certificate.trim().is_empty() {
+            return Err(ConfigError::MissingCertificate);
+        }
+        Ok(())
+    }
+}
+
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct CreateOktaAddonRequest<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] pub tag: TypeTag<6647130>,
+    #[b(1)] pub project_id: CowStr<'a>,
+    #[b(2)] pub config: OktaConfig<'a>,
+}
+
+impl<'a> CreateOktaAddonRequest<'a> {
+    pub fn new(project_id: impl Into<CowStr<'a>>, config: OktaConfig<'a>) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            project_id: project_id.into(),
+            config,
+        }
+    }
+}
+
+/// Authenticate to a project with an Okta access token.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct OktaAuthRequest<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] pub tag: TypeTag<9063721>,
+    #[b(1)] pub access_token: CowStr<'a>,
+    #[b(2)] pub project_id: CowStr<'a>,
+}
+
+impl<'a> OktaAuthRequest<'a> {
+    pub fn new(access_token: impl Into<CowStr<'a>>, project_id: impl Into<CowStr<'a>>) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            access_token: access_token.into(),
+            project_id: project_id.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CERT: &str = "-----BEGIN CERTIFICATE-----\nMIIB\n-----END CERTIFICATE-----";
+
+    #[test]
+    fn validate() {
+        let c = OktaConfig::new("https://acme.okta.com", CERT, "c1");
+        assert_eq!(Ok(()), c.validate());
+
+        let c = OktaConfig::new("http://acme.okta.com", CERT, "c1");
+        assert_eq!(Err(ConfigError::InsecureTenantUrl), c.validate());
+
+        let c = OktaConfig::new("https://acme.okta.com", " ", "c1");
+        assert_eq!(Err(ConfigError::MissingCertificate), c.validate());
+    }
+
+    #[test]
+    fn create_okta_addon_request() {
+        let c = OktaConfig::new("https://acme.okta.com", CERT, "c1")
+            .with_attribute_mapping("email", "email")
+            .with_attribute_mapping("groups", "role");
+        let req = CreateOktaAddonRequest::new("p1", c);
+        let bytes = minicbor::to_vec(&req).unwrap();
+        assert_eq!(req, minicbor::decode(&bytes).unwrap());
+
+        let req = OktaAuthRequest::new("t0k3n", "p1");
+        let bytes = minicbor::to_vec(&req).unwrap();
+        assert_eq!(req, minicbor::decode(&bytes).unwrap());
+    }
+}
@@ -14,6 +14,20 @@ use ockam_node::tokio;
 use crate::error::ApiError;
 use crate::multiaddr_to_addr;
 
+/// The controller path template of the projects of a space, see
+/// [`RoutePattern`](crate::routing::RoutePattern)
+pub const PROJECTS_PATH: &str = "/v0/spaces/:space_id/projects";
+
+/// The controller path of the projects of a space
+pub fn projects_path(space_id: &str) -> String {
+    format!("/v0/spaces/{space_id}/projects")
+}
+
+/// The controller path of a single project
+pub fn project_path(space_id: &str, project_id: &str) -> String {
+    format!("/v0/spaces/{space_id}/projects/{project_id}")
+}
+
 #[derive(Encode, Decode, Serialize, Deserialize, Debug, Default)]
 #[cbor(map)]
 pub struct Project<'a> {
@@ -118,6 +132,14 @@ impl Project<'_> {
     }
 }
 
+/// Description of a project
+pub type ProjectInfo<'a> = Project<'a>;
+
+/// Response body for a list of projects
+///
+/// The controller responds with a plain array of projects.
+pub type ListProjectsResponse<'a> = Vec<ProjectInfo<'a>>;
+
 #[derive(Encode, Decode, Debug)]
 #[cfg_attr(test, derive(Clone))]
 #[rustfmt::skip]
@@ -149,6 +171,28 @@ impl<'a> CreateProject<'a> {
     }
 }
 
+/// Request body to create a project
+pub type CreateProjectRequest<'a> = CreateProject<'a>;
+
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct DeleteProjectRequest<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] pub tag: TypeTag<5830514>,
+    #[b(1)] pub project_id: CowStr<'a>,
+}
+
+impl<'a> DeleteProjectRequest<'a> {
+    pub fn new(project_id: impl Into<CowStr<'a>>) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            project_id: project_id.into(),
+        }
+    }
+}
+
 #[derive(Encode, Decode, Debug)]
 #[cfg_attr(test, derive(Clone))]
 #[rustfmt::skip]
@@ -432,4 +476,61 @@ mod tests {
         let socket_addr = p.access_route_socket_addr().unwrap();
         assert_eq!(&socket_addr, "node.dnsaddr.com:4000");
     }
+
+    #[test]
+    fn project_without_optional_fields() {
+        let p = Project {
+            id: "p1".into(),
+            name: "default".into(),
+            space_name: "s".into(),
+            access_route: "".into(),
+            space_id: "s1".into(),
+            ..Default::default()
+        };
+        assert!(!p.is_ready());
+
+        let cbor = minicbor::to_vec(&p).unwrap();
+        let len = minicbor::Decoder::new(&cbor).map().unwrap();
+        let expected = if cfg!(feature = "tag") { 8 } else { 7 };
+        assert_eq!(Some(expected), len);
+
+        let p: Project = minicbor::decode(&cbor).unwrap();
+        assert!(p.identity.is_none());
+        assert!(p.authority_access_route.is_none());
+        assert!(p.authority_identity.is_none());
+        assert!(!p.is_ready());
+    }
+
+    #[test]
+    fn paths() {
+        use crate::routing::RoutePattern;
+
+        assert_eq!("/v0/spaces/s1/projects", projects_path("s1"));
+        assert_eq!("/v0/spaces/s1/projects/p1", project_path("s1", "p1"));
+
+        let p = RoutePattern::new(PROJECTS_PATH).unwrap();
+        let path = projects_path("s1");
+        let m = p.extract(&path).unwrap();
+        assert_eq!(Some("s1"), m.get("space_id"));
+        assert!(!p.matches(&project_path("s1", "p1")))
+    }
+
+    #[test]
+    fn list_and_delete_projects_roundtrip() {
+        let mut g = Gen::new(100);
+        let res: ListProjectsResponse = vec![Pr::arbitrary(&mut g).0, Pr::arbitrary(&mut g).0];
+        let bytes = minicbor::to_vec(&res).unwrap();
+        let dec: ListProjectsResponse = minicbor::decode(&bytes).unwrap();
+        assert_eq!(2, dec.len());
+        for (a, b) in res.iter().zip(&dec) {
+            assert_eq!(a.id, b.id);
+            assert_eq!(a.space_id, b.space_id);
+            assert_eq!(a.identity, b.identity);
+            assert_eq!(a.is_ready(), b.is_ready())
+        }
+
+        let req = DeleteProjectRequest::new("p1");
+        let bytes = minicbor::to_vec(&req).unwrap();
+        assert_eq!(req, minicbor::decode(&bytes).unwrap());
+    }
 }
@@ -5,7 +5,7 @@ use ockam_core::CowStr;
 #[cfg(feature = "tag")]
 use ockam_core::TypeTag;
 
-#[derive(Encode, Decode, Serialize, Debug)]
+#[derive(Encode, Decode, Serialize, Debug, PartialEq, Eq)]
 #[rustfmt::skip]
 #[cbor(map)]
 pub struct Space<'a> {
@@ -15,6 +15,8 @@ pub struct Space<'a> {
     #[b(1)] pub id: CowStr<'a>,
     #[b(2)] pub name: CowStr<'a>,
     #[b(3)] pub users: Vec<CowStr<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[n(4)] pub subscription: Option<SubscriptionInfo>,
 }
 
 impl Clone for Space<'_> {
@@ -31,10 +33,51 @@ impl Space<'_> {
             id: self.id.to_owned(),
             name: self.name.to_owned(),
             users: self.users.iter().map(|x| x.to_owned()).collect(),
+            subscription: self.subscription.clone(),
         }
     }
 }
 
+/// Description of a space
+pub type SpaceInfo<'a> = Space<'a>;
+
+/// Response body for a list of spaces
+///
+/// The controller responds with a plain array of spaces.
+pub type ListSpacesResponse<'a> = Vec<SpaceInfo<'a>>;
+
+/// The subscription a space is billed under
+#[derive(Encode, Decode, Serialize, Debug, Clone, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct SubscriptionInfo {
+    #[cfg(feature = "tag")]
+    #[serde(skip)]
+    #[n(0)] pub tag: TypeTag<6108372>,
+    #[n(1)] pub plan: SubscriptionPlan,
+    #[n(2)] pub is_active: bool,
+}
+
+impl SubscriptionInfo {
+    pub fn new(plan: SubscriptionPlan, is_active: bool) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            plan,
+            is_active,
+        }
+    }
+}
+
+#[derive(Encode, Decode, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(index_only)]
+pub enum SubscriptionPlan {
+    #[n(0)] Free,
+    #[n(1)] Team,
+    #[n(2)] Enterprise,
+}
+
 #[derive(Encode, Decode, Debug)]
 #[cfg_attr(test, derive(Clone))]
 #[rustfmt::skip]
@@ -57,6 +100,28 @@ impl<'a> CreateSpace<'a> {
     }
 }
 
+/// Request body to create a space
+pub type CreateSpaceRequest<'a> = CreateSpace<'a>;
+
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct DeleteSpaceRequest<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] pub tag: TypeTag<4402941>,
+    #[b(1)] pub id: CowStr<'a>,
+}
+
+impl<'a> DeleteSpaceRequest<'a> {
+    pub fn new(id: impl Into<CowStr<'a>>) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            id: id.into(),
+        }
+    }
+}
+
 mod node {
     use minicbor::Decoder;
     use tracing::trace;
@@ -156,6 +221,96 @@ pub mod tests {
 
     use super::*;
 
+    fn space_info<'a>(id: &'a str, subscription: Option<SubscriptionInfo>) -> SpaceInfo<'a> {
+        SpaceInfo {
+            #[cfg(feature = "tag")]
+            tag: Default::default(),
+            id: id.into(),
+            name: "space".into(),
+            users: vec!["alice@example.com".into(), "bob@example.com".into()],
+            subscription,
+        }
+    }
+
+    #[test]
+    fn subscription_info_roundtrip() {
+        for plan in [
+            SubscriptionPlan::Free,
+            SubscriptionPlan::Team,
+            SubscriptionPlan::Enterprise,
+        ] {
+            for is_active in [true, false] {
+                let sub = SubscriptionInfo::new(plan, is_active);
+                let bytes = minicbor::to_vec(&sub).unwrap();
+                assert_eq!(sub, minicbor::decode(&bytes).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn list_spaces_response_roundtrip() {
+        let res: ListSpacesResponse = vec![
+            space_info("s1", None),
+            space_info(
+                "s2",
+                Some(SubscriptionInfo::new(SubscriptionPlan::Team, true)),
+            ),
+            space_info(
+                "s3",
+                Some(SubscriptionInfo::new(SubscriptionPlan::Enterprise, false)),
+            ),
+        ];
+        let bytes = minicbor::to_vec(&res).unwrap();
+        let dec: ListSpacesResponse = minicbor::decode(&bytes).unwrap();
+        assert_eq!(res, dec);
+        assert!(dec[0].subscription.is_none());
+        assert_eq!(
+            Some(SubscriptionPlan::Team),
+            dec[1].subscription.as_ref().map(|s| s.plan)
+        );
+        assert_eq!(
+            Some(false),
+            dec[2].subscription.as_ref().map(|s| s.is_active)
+        );
+    }
+
+    #[test]
+    fn create_and_delete_space_request_roundtrip() {
+        let users = ["alice@example.com"];
+        let req = CreateSpaceRequest::new("space", &users);
+        let bytes = minicbor::to_vec(&req).unwrap();
+        let dec: CreateSpaceRequest = minicbor::decode(&bytes).unwrap();
+        assert_eq!(req.name, dec.name);
+        assert_eq!(req.users, dec.users);
+
+        let req = DeleteSpaceRequest::new("s1");
+        let bytes = minicbor::to_vec(&req).unwrap();
+        assert_eq!(req, minicbor::decode(&bytes).unwrap());
+    }
+
+    #[test]
+    fn space_with_subscription_roundtrip() {
+        for plan in [
+            SubscriptionPlan::Free,
+            SubscriptionPlan::Team,
+            SubscriptionPlan::Enterprise,
+        ] {
+            let space = Space {
+                #[cfg(feature = "tag")]
+                tag: Default::default(),
+                id: "s1".into(),
+                name: "space".into(),
+                users: vec!["alice@example.com".into()],
+                subscription: Some(SubscriptionInfo::new(plan, true)),
+            };
+            let bytes = minicbor::to_vec(&space).unwrap();
+            let dec: Space = minicbor::decode(&bytes).unwrap();
+            let sub = dec.subscription.unwrap();
+            assert_eq!(plan, sub.plan);
+            assert!(sub.is_active);
+        }
+    }
+
     mod schema {
         use cddl_cat::validate_cbor_bytes;
         use quickcheck::{quickcheck, TestResult};
@@ -175,6 +330,16 @@ pub mod tests {
                     id: String::arbitrary(g).into(),
                     name: String::arbitrary(g).into(),
                     users: vec![String::arbitrary(g).into(), String::arbitrary(g).into()],
+                    subscription: bool::arbitrary(g).then(|| {
+                        let plan = *g
+                            .choose(&[
+                                SubscriptionPlan::Free,
+                                SubscriptionPlan::Team,
+                                SubscriptionPlan::Enterprise,
+                            ])
+                            .unwrap();
+                        SubscriptionInfo::new(plan, bool::arbitrary(g))
+                    }),
                 })
             }
         }
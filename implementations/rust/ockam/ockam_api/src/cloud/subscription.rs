@@ -5,6 +5,8 @@ use ockam_core::CowStr;
 #[cfg(feature = "tag")]
 use ockam_core::TypeTag;
 
+use crate::cloud::space::SubscriptionPlan;
+
 #[derive(Encode, Decode, Debug)]
 #[cfg_attr(test, derive(Clone))]
 #[rustfmt::skip]
@@ -85,6 +87,73 @@ pub struct Subscription<'a> {
     pub space_id: Option<CowStr<'a>>,
 }
 
+#[derive(Encode, Decode, Serialize, Debug, Clone, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct SubscriptionDetails<'a> {
+    #[cfg(feature = "tag")]
+    #[serde(skip)]
+    #[n(0)] pub tag: TypeTag<2715306>,
+    #[b(1)] pub id: CowStr<'a>,
+    #[n(2)] pub plan: SubscriptionPlan,
+    #[b(3)] pub space_id: CowStr<'a>,
+    #[n(4)] pub is_active: bool,
+    /// Seconds since the Unix epoch
+    #[n(5)] pub billing_cycle_start: u64,
+    /// Seconds since the Unix epoch
+    #[n(6)] pub billing_cycle_end: u64,
+    /// End of the trial period in seconds since the Unix epoch, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[n(7)] pub trial_ends_at: Option<u64>,
+}
+
+impl<'a> SubscriptionDetails<'a> {
+    /// Check if the subscription is in its trial period at `now` (seconds since the Unix epoch).
+    pub fn is_in_trial(&self, now: u64) -> bool {
+        matches!(self.trial_ends_at, Some(t) if now < t)
+    }
+}
+
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct UpdateSubscriptionRequest<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] pub tag: TypeTag<8430952>,
+    #[b(1)] pub space_id: CowStr<'a>,
+    #[n(2)] pub plan: SubscriptionPlan,
+}
+
+impl<'a> UpdateSubscriptionRequest<'a> {
+    pub fn new(space_id: impl Into<CowStr<'a>>, plan: SubscriptionPlan) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            space_id: space_id.into(),
+            plan,
+        }
+    }
+}
+
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct GetSubscriptionResponse<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] pub tag: TypeTag<5068213>,
+    #[b(1)] pub subscription: SubscriptionDetails<'a>,
+}
+
+impl<'a> GetSubscriptionResponse<'a> {
+    pub fn new(subscription: SubscriptionDetails<'a>) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            subscription,
+        }
+    }
+}
+
 mod node {
     use minicbor::Decoder;
     use tracing::trace;
@@ -209,3 +278,52 @@ mod node {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn details(trial_ends_at: Option<u64>) -> SubscriptionDetails<'static> {
+        SubscriptionDetails {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            id: "sub1".into(),
+            plan: SubscriptionPlan::Team,
+            space_id: "s1".into(),
+            is_active: true,
+            billing_cycle_start: 1000,
+            billing_cycle_end: 3000,
+            trial_ends_at,
+        }
+    }
+
+    #[test]
+    fn is_in_trial() {
+        let d = details(Some(2000));
+        assert!(d.is_in_trial(0));
+        assert!(d.is_in_trial(1999));
+        assert!(!d.is_in_trial(2000));
+        assert!(!d.is_in_trial(2001));
+        assert!(!details(None).is_in_trial(0))
+    }
+
+    #[test]
+    fn roundtrip() {
+        let with = details(Some(2000));
+        let without = details(None);
+        for d in [&with, &without] {
+            let res = GetSubscriptionResponse::new(d.clone());
+            let bytes = minicbor::to_vec(&res).unwrap();
+            let dec: GetSubscriptionResponse = minicbor::decode(&bytes).unwrap();
+            assert_eq!(res, dec);
+            assert_eq!(d.trial_ends_at, dec.subscription.trial_ends_at);
+        }
+        let with = minicbor::to_vec(&with).unwrap();
+        let without = minicbor::to_vec(&without).unwrap();
+        assert!(without.len() < with.len());
+
+        let req = UpdateSubscriptionRequest::new("s1", SubscriptionPlan::Enterprise);
+        let bytes = minicbor::to_vec(&req).unwrap();
+        assert_eq!(req, minicbor::decode(&bytes).unwrap())
+    }
+}
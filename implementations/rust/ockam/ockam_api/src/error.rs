@@ -1,5 +1,6 @@
 use core::fmt;
 
+use ockam_core::api::{Status, UnknownStatus};
 use ockam_core::compat::io;
 use ockam_core::errcode::{Kind, Origin};
 
@@ -22,6 +23,31 @@ impl ApiError {
     {
         ockam_core::Error::new(Origin::Application, Kind::Unknown, e)
     }
+
+    pub fn not_found() -> Self {
+        ApiError(ErrorImpl::NotFound)
+    }
+
+    pub fn unauthorized() -> Self {
+        ApiError(ErrorImpl::Unauthorized)
+    }
+
+    pub fn bad_request<T: fmt::Display>(m: T) -> Self {
+        ApiError(ErrorImpl::BadRequest(m.to_string()))
+    }
+
+    /// The response status corresponding to this error.
+    pub fn status(&self) -> Status {
+        match &self.0 {
+            ErrorImpl::CborDecode(_) | ErrorImpl::SerdeJson(_) | ErrorImpl::BadRequest(_) => {
+                Status::BadRequest
+            }
+            ErrorImpl::NotFound => Status::NotFound,
+            ErrorImpl::Unauthorized => Status::Unauthorized,
+            ErrorImpl::Io(_) => Status::ServiceUnavailable,
+            ErrorImpl::CborEncode(_) | ErrorImpl::UnknownStatus(_) => Status::InternalServerError,
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -29,6 +55,11 @@ enum ErrorImpl {
     CborDecode(minicbor::decode::Error),
     CborEncode(minicbor::encode::Error<io::Error>),
     SerdeJson(serde_json::Error),
+    Io(io::Error),
+    NotFound,
+    Unauthorized,
+    BadRequest(String),
+    UnknownStatus(UnknownStatus),
 }
 
 impl fmt::Display for ApiError {
@@ -37,6 +68,11 @@ impl fmt::Display for ApiError {
             ErrorImpl::CborEncode(e) => e.fmt(f),
             ErrorImpl::CborDecode(e) => e.fmt(f),
             ErrorImpl::SerdeJson(e) => e.fmt(f),
+            ErrorImpl::Io(e) => e.fmt(f),
+            ErrorImpl::NotFound => f.write_str("not found"),
+            ErrorImpl::Unauthorized => f.write_str("unauthorized"),
+            ErrorImpl::BadRequest(m) => write!(f, "bad request: {m}"),
+            ErrorImpl::UnknownStatus(e) => e.fmt(f),
         }
     }
 }
@@ -48,6 +84,9 @@ impl ockam_core::compat::error::Error for ApiError {
             ErrorImpl::CborDecode(e) => Some(e),
             ErrorImpl::CborEncode(e) => Some(e),
             ErrorImpl::SerdeJson(e) => Some(e),
+            ErrorImpl::Io(e) => Some(e),
+            ErrorImpl::UnknownStatus(e) => Some(e),
+            ErrorImpl::NotFound | ErrorImpl::Unauthorized | ErrorImpl::BadRequest(_) => None,
         }
     }
 }
@@ -70,8 +109,61 @@ impl From<serde_json::Error> for ApiError {
     }
 }
 
+impl From<io::Error> for ApiError {
+    fn from(e: io::Error) -> Self {
+        ApiError(ErrorImpl::Io(e))
+    }
+}
+
+impl From<UnknownStatus> for ApiError {
+    fn from(e: UnknownStatus) -> Self {
+        ApiError(ErrorImpl::UnknownStatus(e))
+    }
+}
+
 impl From<ApiError> for ockam_core::Error {
     fn from(e: ApiError) -> Self {
-        ockam_core::Error::new(Origin::Application, Kind::Invalid, e)
+        let kind = match &e.0 {
+            ErrorImpl::Io(_) => Kind::Io,
+            ErrorImpl::NotFound => Kind::NotFound,
+            _ => Kind::Invalid,
+        };
+        ockam_core::Error::new(Origin::Application, kind, e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversions() {
+        let e = minicbor::decode::<u8>(&[]).unwrap_err();
+        let e = ApiError::from(e);
+        assert_eq!(Status::BadRequest, e.status());
+        let _ = ockam_core::Error::from(e);
+
+        let e = minicbor::encode::Error::<io::Error>::write(io::ErrorKind::Other.into());
+        let e = ApiError::from(e);
+        assert_eq!(Status::InternalServerError, e.status());
+        let _ = ockam_core::Error::from(e);
+
+        let e = serde_json::from_str::<u8>("x").unwrap_err();
+        assert_eq!(Status::BadRequest, ApiError::from(e).status());
+
+        let e = ApiError::from(io::Error::from(io::ErrorKind::BrokenPipe));
+        assert_eq!(Status::ServiceUnavailable, e.status());
+        assert_eq!(Kind::Io, ockam_core::Error::from(e).code().kind);
+
+        let e = ockam_core::Error::from(ApiError::not_found());
+        assert_eq!(Kind::NotFound, e.code().kind);
+        assert_eq!(Status::Unauthorized, ApiError::unauthorized().status());
+        let e = ApiError::bad_request("missing name");
+        assert_eq!(Status::BadRequest, e.status());
+        assert_eq!("bad request: missing name", e.to_string());
+
+        let e = ApiError::from(Status::try_from(999).unwrap_err());
+        assert_eq!(Status::InternalServerError, e.status());
+        assert_eq!("unknown status code: 999", e.to_string())
     }
 }
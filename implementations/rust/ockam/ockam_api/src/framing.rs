@@ -0,0 +1,125 @@
+//! Length-prefixed framing of encoded requests and responses.
+//!
+//! CBOR items sent as discrete messages are self-delimiting, but when
+//! written to a stream transport the message boundaries are lost. A
+//! [`Frame`] restores them by prefixing every payload with its length
+//! as a 4-byte big-endian integer.
+
+/// Number of bytes used for the length prefix.
+pub const PREFIX_LEN: usize = 4;
+
+/// Length-prefix framing of byte payloads.
+pub struct Frame;
+
+impl Frame {
+    /// Prepend the length of the payload to a copy of it.
+    ///
+    /// # Panics
+    ///
+    /// If the payload is larger than `u32::MAX` bytes.
+    pub fn encode(payload: &[u8]) -> Vec<u8> {
+        let len = u32::try_from(payload.len()).expect("payload too large");
+        let mut buf = Vec::with_capacity(PREFIX_LEN + payload.len());
+        buf.extend_from_slice(&len.to_be_bytes());
+        buf.extend_from_slice(payload);
+        buf
+    }
+
+    /// Split the first complete frame off the given buffer.
+    ///
+    /// Returns the frame payload and the remaining bytes, or `None` if the
+    /// buffer does not yet contain a complete frame.
+    pub fn decode(buf: &[u8]) -> Option<(&[u8], &[u8])> {
+        let len = Self::payload_len(buf)?;
+        let frame = buf.get(PREFIX_LEN..PREFIX_LEN + len)?;
+        Some((frame, &buf[PREFIX_LEN + len..]))
+    }
+
+    fn payload_len(buf: &[u8]) -> Option<usize> {
+        let prefix = buf.get(..PREFIX_LEN)?;
+        let len = u32::from_be_bytes(prefix.try_into().ok()?);
+        usize::try_from(len).ok()
+    }
+}
+
+#[cfg(feature = "framed-reader")]
+pub use reader::FramedReader;
+
+#[cfg(feature = "framed-reader")]
+mod reader {
+    use super::{Frame, PREFIX_LEN};
+    use bytes::{Buf, Bytes, BytesMut};
+    use std::io;
+    use tokio::io::{AsyncRead, AsyncReadExt};
+
+    /// Reads length-prefixed frames from an [`AsyncRead`] source.
+    pub struct FramedReader<R> {
+        inner: R,
+        buf: BytesMut,
+    }
+
+    impl<R: AsyncRead + Unpin> FramedReader<R> {
+        pub fn new(inner: R) -> Self {
+            FramedReader {
+                inner,
+                buf: BytesMut::new(),
+            }
+        }
+
+        /// Read the next frame payload.
+        ///
+        /// Returns `None` if the source is exhausted at a frame boundary and
+        /// fails with [`io::ErrorKind::UnexpectedEof`] if it ends in the
+        /// middle of a frame.
+        pub async fn next_frame(&mut self) -> io::Result<Option<Bytes>> {
+            loop {
+                if let Some(len) = Frame::payload_len(&self.buf) {
+                    if self.buf.len() >= PREFIX_LEN + len {
+                        self.buf.advance(PREFIX_LEN);
+                        return Ok(Some(self.buf.split_to(len).freeze()));
+                    }
+                }
+                if self.inner.read_buf(&mut self.buf).await? == 0 {
+                    if self.buf.is_empty() {
+                        return Ok(None);
+                    }
+                    return Err(io::ErrorKind::UnexpectedEof.into());
+                }
+            }
+        }
+
+        pub fn into_inner(self) -> R {
+            self.inner
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn concatenated_frames() {
+        let mut stream = Frame::encode(b"first");
+        stream.extend(Frame::encode(b""));
+        stream.extend(Frame::encode(b"third"));
+
+        let (a, rest) = Frame::decode(&stream).unwrap();
+        let (b, rest) = Frame::decode(rest).unwrap();
+        let (c, rest) = Frame::decode(rest).unwrap();
+        assert_eq!(b"first", a);
+        assert!(b.is_empty());
+        assert_eq!(b"third", c);
+        assert!(rest.is_empty());
+        assert!(Frame::decode(rest).is_none())
+    }
+
+    #[test]
+    fn partial_frames() {
+        let frame = Frame::encode(b"payload");
+        for i in 0..frame.len() {
+            assert!(Frame::decode(&frame[..i]).is_none())
+        }
+        assert!(Frame::decode(&frame).is_some())
+    }
+}
@@ -187,7 +187,7 @@ impl<V: IdentityVault> IdentityService<V> {
                 }
                 _ => Self::response_for_bad_request(req, "unknown path", enc),
             },
-            Get | Put | Patch | Delete => {
+            Get | Put | Patch | Delete | Head | Options => {
                 Self::response_for_bad_request(req, "unknown method", enc)
             }
         }
@@ -1,11 +1,16 @@
 pub mod auth;
 pub mod authenticator;
+pub mod client;
 pub mod cloud;
 pub mod config;
 pub mod echoer;
 pub mod error;
+pub mod framing;
 pub mod identity;
+pub mod middleware;
 pub mod nodes;
+pub mod pagination;
+pub mod routing;
 pub mod uppercase;
 pub mod vault;
 pub mod verifier;
@@ -0,0 +1,116 @@
+//! Wrappers adding behaviour to a [`Client`].
+
+use std::time::Instant;
+
+use minicbor::Encode;
+use ockam_core::api::{RequestBuilder, Response};
+use tracing::Level;
+
+use crate::client::{Client, ClientError};
+
+const TARGET: &str = "ockam_api::middleware";
+
+/// Emit an event at a level only known at runtime.
+///
+/// `Level` is not an enum, hence the catch-all arm for `TRACE`.
+macro_rules! event_at {
+    ($level:expr, $($args:tt)+) => {
+        match $level {
+            Level::ERROR => error!(target: TARGET, $($args)+),
+            Level::WARN => warn!(target: TARGET, $($args)+),
+            Level::INFO => info!(target: TARGET, $($args)+),
+            Level::DEBUG => debug!(target: TARGET, $($args)+),
+            _ => trace!(target: TARGET, $($args)+),
+        }
+    };
+}
+
+/// A client which logs every request and its response.
+#[derive(Debug)]
+pub struct RequestLogger<C> {
+    inner: C,
+    level: Level,
+}
+
+impl<C: Client> RequestLogger<C> {
+    pub fn new(inner: C, level: Level) -> Self {
+        RequestLogger { inner, level }
+    }
+
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+}
+
+impl<C: Client> Client for RequestLogger<C> {
+    fn send<T: Encode<()>>(
+        &mut self,
+        req: RequestBuilder<T>,
+    ) -> Result<(Response, Vec<u8>), ClientError> {
+        let hdr = req.header();
+        let id = hdr.id();
+        let method = hdr.method_str();
+        let path = hdr.path().to_string();
+        event_at!(self.level, id = %id, %method, %path, "sending request");
+        let start = Instant::now();
+        let result = self.inner.send(req);
+        let elapsed_ms = start.elapsed().as_millis() as u64;
+        match &result {
+            Ok((res, _)) => {
+                let status = res.status().map(|s| s.as_u16()).unwrap_or_default();
+                event_at!(self.level, id = %id, %method, %path, %status, %elapsed_ms, "received response")
+            }
+            Err(err) => {
+                event_at!(self.level, id = %id, %method, %path, %err, %elapsed_ms, "request failed")
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::mock::MockClient;
+    use ockam_core::api::test_support::Recorder;
+    use ockam_core::api::{Request, Status};
+
+    #[test]
+    fn logs_requests_and_responses() {
+        let mock = MockClient::new();
+        mock.expect(Status::Ok, minicbor::to_vec("n1").unwrap());
+        mock.expect(Status::NotFound, Vec::new());
+        let mut client = RequestLogger::new(mock.clone(), Level::INFO);
+
+        let rec = Recorder::default();
+        let (ok, not_found) = rec.with_default(|| {
+            let ok = Request::get("/nodes/n1");
+            let ok_id = ok.header().id();
+            client.send(ok).unwrap();
+            let not_found = Request::delete("/nodes/n2");
+            let not_found_id = not_found.header().id();
+            client.send(not_found).unwrap();
+            (ok_id, not_found_id)
+        });
+        mock.assert_request_count(2);
+
+        let events = rec.events();
+        assert_eq!(4, events.len());
+        assert!(events.iter().all(|(l, _)| *l == Level::INFO));
+
+        let (_, sent) = &events[0];
+        assert!(sent.contains(&format!("id={ok}")));
+        assert!(sent.contains("method=GET"));
+        assert!(sent.contains("path=/nodes/n1"));
+        let (_, received) = &events[1];
+        assert!(received.contains(&format!("id={ok}")));
+        assert!(received.contains("status=200"));
+        assert!(received.contains("elapsed_ms="));
+
+        let (_, received) = &events[3];
+        assert!(received.contains(&format!("id={not_found}")));
+        assert!(received.contains("method=DELETE"));
+        assert!(received.contains("path=/nodes/n2"));
+        assert!(received.contains("status=404"))
+    }
+}
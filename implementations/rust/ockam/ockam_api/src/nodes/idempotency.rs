@@ -0,0 +1,151 @@
+//! Deduplication of retried requests by their idempotency key.
+
+use std::collections::VecDeque;
+
+use ockam_core::api::{parse_response, Cbor, Id, Method, Response, Status};
+use ockam_core::Result;
+
+/// The number of responses remembered by default.
+const DEFAULT_CAPACITY: usize = 256;
+
+/// The successful responses to recent requests with an idempotency key.
+///
+/// A request with the same key, method and path as a remembered one is
+/// answered with the remembered status and body, instead of being handled
+/// again. Once full, the oldest response is forgotten.
+#[derive(Debug)]
+pub(crate) struct IdempotencyCache {
+    entries: VecDeque<Entry>,
+    capacity: usize,
+}
+
+#[derive(Debug)]
+struct Entry {
+    key: String,
+    method: Method,
+    path: String,
+    status: Status,
+    body: Option<Vec<u8>>,
+}
+
+impl Default for IdempotencyCache {
+    fn default() -> Self {
+        IdempotencyCache::with_capacity(DEFAULT_CAPACITY)
+    }
+}
+
+impl IdempotencyCache {
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        IdempotencyCache {
+            entries: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Get the remembered response to the given request, addressed to `re`.
+    pub(crate) fn get(
+        &self,
+        re: Id,
+        key: &str,
+        method: Method,
+        path: &str,
+    ) -> Result<Option<Vec<u8>>> {
+        let e = match self
+            .entries
+            .iter()
+            .find(|e| e.key == key && e.method == method && e.path == path)
+        {
+            Some(e) => e,
+            None => return Ok(None),
+        };
+        let res = Response::builder(re, e.status);
+        let bytes = match &e.body {
+            Some(b) => res.body(Cbor(b)).to_vec()?,
+            None => res.to_vec()?,
+        };
+        Ok(Some(bytes))
+    }
+
+    /// Remember the encoded response to the given request, if successful.
+    pub(crate) fn insert(
+        &mut self,
+        key: &str,
+        method: Method,
+        path: &str,
+        response: &[u8],
+    ) -> Result<()> {
+        let (hdr, body) = parse_response(response)?;
+        let status = match hdr.status() {
+            Some(s) if s.is_success() => s,
+            _ => return Ok(()),
+        };
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(Entry {
+            key: key.to_string(),
+            method,
+            path: path.to_string(),
+            status,
+            body: if hdr.has_body() {
+                Some(body.to_vec())
+            } else {
+                None
+            },
+        });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response(status: Status, body: &str) -> Vec<u8> {
+        Response::builder(Id::fresh(), status)
+            .body(body)
+            .to_vec()
+            .unwrap()
+    }
+
+    #[test]
+    fn remembers_successful_responses() {
+        let mut cache = IdempotencyCache::default();
+        let ok = response(Status::Ok, "v1");
+        let conflict = response(Status::Conflict, "v1");
+        cache
+            .insert("k1", Method::Post, "/node/vault", &ok)
+            .unwrap();
+        cache
+            .insert("k2", Method::Post, "/node/vault", &conflict)
+            .unwrap();
+
+        let re = Id::fresh();
+        let bytes = cache.get(re, "k1", Method::Post, "/node/vault").unwrap();
+        let bytes = bytes.unwrap();
+        let (res, body) = parse_response(&bytes).unwrap();
+        assert_eq!(re, res.re());
+        assert_eq!(Some(Status::Ok), res.status());
+        assert_eq!("v1", res.decode_body::<&str>(body).unwrap());
+
+        // Same key, but a different request:
+        let other = cache.get(re, "k1", Method::Post, "/node/identity").unwrap();
+        assert!(other.is_none());
+        // Failed requests may be retried:
+        let failed = cache.get(re, "k2", Method::Post, "/node/vault").unwrap();
+        assert!(failed.is_none())
+    }
+
+    #[test]
+    fn bounded() {
+        let mut cache = IdempotencyCache::with_capacity(2);
+        for key in ["k1", "k2", "k3"] {
+            let res = response(Status::Ok, key);
+            cache.insert(key, Method::Post, "/", &res).unwrap()
+        }
+        let re = Id::fresh();
+        assert!(cache.get(re, "k1", Method::Post, "/").unwrap().is_none());
+        assert!(cache.get(re, "k2", Method::Post, "/").unwrap().is_some());
+        assert!(cache.get(re, "k3", Method::Post, "/").unwrap().is_some())
+    }
+}
@@ -1,4 +1,5 @@
 mod config;
+mod idempotency;
 pub mod registry;
 
 pub mod service;
@@ -1,12 +1,42 @@
 use minicbor::{Decode, Encode};
 use ockam_core::compat::borrow::Cow;
 
-use ockam_core::CowBytes;
+use ockam_core::{CowBytes, CowStr};
 
 #[cfg(feature = "tag")]
 use ockam_core::TypeTag;
 
-/// Response body when instructing a node to create a Secure Channel
+/// Description of a node's identity
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct IdentityInfo<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] tag: TypeTag<3390261>,
+    /// The identifier, i.e. the hex fingerprint of the identity
+    #[b(1)] pub identifier: CowStr<'a>,
+    /// The serialized change history
+    #[b(2)] pub change_history: CowBytes<'a>,
+    #[n(3)] pub is_default: bool,
+}
+
+impl<'a> IdentityInfo<'a> {
+    pub fn new(
+        identifier: impl Into<CowStr<'a>>,
+        change_history: impl Into<CowBytes<'a>>,
+        is_default: bool,
+    ) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            identifier: identifier.into(),
+            change_history: change_history.into(),
+            is_default,
+        }
+    }
+}
+
+/// Response body when instructing a node to create an identity
 #[derive(Debug, Clone, Decode, Encode)]
 #[rustfmt::skip]
 #[cbor(map)]
@@ -14,14 +44,56 @@ pub struct CreateIdentityResponse<'a> {
     #[cfg(feature = "tag")]
     #[n(0)] tag: TypeTag<2187575>,
     #[b(1)] pub identity_id: Cow<'a, str>,
+    #[b(2)] pub identity: Option<IdentityInfo<'a>>,
 }
 
 impl<'a> CreateIdentityResponse<'a> {
-    pub fn new(identity_id: impl Into<Cow<'a, str>>) -> Self {
+    pub fn new(identity: IdentityInfo<'a>) -> Self {
         Self {
             #[cfg(feature = "tag")]
             tag: TypeTag,
-            identity_id: identity_id.into(),
+            identity_id: Cow::Owned(identity.identifier.to_string()),
+            identity: Some(identity),
+        }
+    }
+}
+
+/// Response body for `GET /node/identity`
+#[derive(Debug, Clone, Decode, Encode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct GetIdentityResponse<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] tag: TypeTag<5850126>,
+    #[b(1)] pub identity: IdentityInfo<'a>,
+}
+
+impl<'a> GetIdentityResponse<'a> {
+    pub fn new(identity: IdentityInfo<'a>) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            identity,
+        }
+    }
+}
+
+/// Request body to select the default identity of a node
+#[derive(Debug, Clone, Decode, Encode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct SetDefaultIdentityRequest<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] tag: TypeTag<1698804>,
+    #[b(1)] pub identifier: CowStr<'a>,
+}
+
+impl<'a> SetDefaultIdentityRequest<'a> {
+    pub fn new(identifier: impl Into<CowStr<'a>>) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            identifier: identifier.into(),
         }
     }
 }
@@ -63,3 +135,24 @@ impl<'a> ShortIdentityResponse<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identity_info() {
+        let history = vec![0xa1, 0x00, 0xff, 0x42, 0x13, 0x37];
+        let info = IdentityInfo::new("P6c20e814", history.clone(), true);
+        let bytes = minicbor::to_vec(&GetIdentityResponse::new(info.clone())).unwrap();
+        let res: GetIdentityResponse = minicbor::decode(&bytes).unwrap();
+        assert_eq!(info, res.identity);
+        assert_eq!(history, res.identity.change_history.as_slice());
+        assert!(matches!(res.identity.change_history.0, Cow::Borrowed(_)));
+
+        let bytes = minicbor::to_vec(&CreateIdentityResponse::new(info.clone())).unwrap();
+        let res: CreateIdentityResponse = minicbor::decode(&bytes).unwrap();
+        assert_eq!("P6c20e814", res.identity_id);
+        assert_eq!(Some(info), res.identity)
+    }
+}
@@ -0,0 +1,174 @@
+//! Kafka inlet and outlet API types
+
+use minicbor::{Decode, Encode};
+
+use ockam_core::CowStr;
+#[cfg(feature = "tag")]
+use ockam_core::TypeTag;
+
+use super::portal::PortalStatus;
+
+/// Request body to create a Kafka inlet
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct CreateKafkaInletRequest<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] tag: TypeTag<6017843>,
+    #[b(1)] pub bind_address: CowStr<'a>,
+    /// The first and last local port to assign to brokers
+    #[n(2)] pub brokers_port_range: (u16, u16),
+    #[b(3)] pub project_route: CowStr<'a>,
+    #[b(4)] pub consumer_route: CowStr<'a>,
+}
+
+impl<'a> CreateKafkaInletRequest<'a> {
+    pub fn new(
+        bind_address: impl Into<CowStr<'a>>,
+        brokers_port_range: (u16, u16),
+        project_route: impl Into<CowStr<'a>>,
+        consumer_route: impl Into<CowStr<'a>>,
+    ) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            bind_address: bind_address.into(),
+            brokers_port_range,
+            project_route: project_route.into(),
+            consumer_route: consumer_route.into(),
+        }
+    }
+}
+
+/// Response body describing a Kafka inlet
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct KafkaInletInfo<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] tag: TypeTag<2740569>,
+    #[b(1)] pub bind_address: CowStr<'a>,
+    #[n(2)] pub brokers_port_range: (u16, u16),
+    #[b(3)] pub project_route: CowStr<'a>,
+    #[b(4)] pub consumer_route: CowStr<'a>,
+    #[b(5)] pub local_address: CowStr<'a>,
+    #[n(6)] pub status: PortalStatus,
+}
+
+impl<'a> KafkaInletInfo<'a> {
+    pub fn new(
+        req: CreateKafkaInletRequest<'a>,
+        local_address: impl Into<CowStr<'a>>,
+        status: PortalStatus,
+    ) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            bind_address: req.bind_address,
+            brokers_port_range: req.brokers_port_range,
+            project_route: req.project_route,
+            consumer_route: req.consumer_route,
+            local_address: local_address.into(),
+            status,
+        }
+    }
+}
+
+/// Request body to create a Kafka outlet
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct CreateKafkaOutletRequest<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] tag: TypeTag<9458127>,
+    #[b(1)] pub bootstrap_server: CowStr<'a>,
+    #[b(2)] pub brokers: Vec<CowStr<'a>>,
+}
+
+impl<'a> CreateKafkaOutletRequest<'a> {
+    pub fn new(bootstrap_server: impl Into<CowStr<'a>>, brokers: Vec<CowStr<'a>>) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            bootstrap_server: bootstrap_server.into(),
+            brokers,
+        }
+    }
+}
+
+/// Response body describing a Kafka outlet
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct KafkaOutletInfo<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] tag: TypeTag<5102374>,
+    #[b(1)] pub bootstrap_server: CowStr<'a>,
+    #[b(2)] pub brokers: Vec<CowStr<'a>>,
+    #[b(3)] pub worker_address: CowStr<'a>,
+}
+
+impl<'a> KafkaOutletInfo<'a> {
+    pub fn new(req: CreateKafkaOutletRequest<'a>, worker_address: impl Into<CowStr<'a>>) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            bootstrap_server: req.bootstrap_server,
+            brokers: req.brokers,
+            worker_address: worker_address.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use minicbor::data::Type;
+    use minicbor::Decoder;
+
+    use super::*;
+
+    #[test]
+    fn port_range() {
+        let req = CreateKafkaInletRequest::new(
+            "127.0.0.1:9092",
+            (4000, 4010),
+            "/project/p1",
+            "/service/c1",
+        );
+        let bytes = minicbor::to_vec(&req).unwrap();
+        assert_eq!(req, minicbor::decode(&bytes).unwrap());
+
+        // The range is a two-element array:
+        let mut d = Decoder::new(&bytes);
+        d.map().unwrap();
+        while d.u8().unwrap() != 2 {
+            d.skip().unwrap()
+        }
+        assert_eq!(Type::Array, d.datatype().unwrap());
+        assert_eq!(Some(2), d.array().unwrap());
+        assert_eq!(4000, d.u16().unwrap());
+        assert_eq!(4010, d.u16().unwrap());
+
+        let info = KafkaInletInfo::new(req, "kafka_inlet_1", PortalStatus::Up);
+        let bytes = minicbor::to_vec(&info).unwrap();
+        let dec: KafkaInletInfo = minicbor::decode(&bytes).unwrap();
+        assert_eq!(info, dec);
+        assert_eq!((4000, 4010), dec.brokers_port_range);
+    }
+
+    #[test]
+    fn brokers() {
+        for brokers in [
+            vec![],
+            vec!["b1:9092".into(), "b2:9092".into(), "b3:9092".into()],
+        ] {
+            let req = CreateKafkaOutletRequest::new("b1:9092", brokers);
+            let bytes = minicbor::to_vec(&req).unwrap();
+            assert_eq!(req, minicbor::decode(&bytes).unwrap());
+
+            let info = KafkaOutletInfo::new(req, "kafka_outlet");
+            let bytes = minicbor::to_vec(&info).unwrap();
+            assert_eq!(info, minicbor::decode(&bytes).unwrap());
+        }
+    }
+}
@@ -0,0 +1,127 @@
+//! Types to send one-off messages through the routing layer of a node
+
+use core::str::FromStr;
+
+use minicbor::{Decode, Encode};
+
+#[cfg(feature = "tag")]
+use ockam_core::TypeTag;
+use ockam_core::{Address, CowBytes, CowStr, Result, Route};
+
+use crate::error::ApiError;
+
+///////////////////-!  REQUEST BODIES
+
+/// Request body to send a message and wait for its reply
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct SendMessageRequest<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] tag: TypeTag<8315472>,
+    /// The route to the receiving worker as dot-separated addresses,
+    /// e.g. `"forwarder.echo"`
+    ///
+    /// Every address may carry a transport type prefix, e.g. `"0#echo"`,
+    /// but must not contain a dot itself.
+    #[b(1)] pub route: CowStr<'a>,
+    #[b(2)] pub payload: CowBytes<'a>,
+    /// How long to wait for a reply
+    #[n(3)] pub timeout_ms: u32,
+}
+
+impl<'a> SendMessageRequest<'a> {
+    pub fn new(
+        route: impl Into<CowStr<'a>>,
+        payload: impl Into<CowBytes<'a>>,
+        timeout_ms: u32,
+    ) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            route: route.into(),
+            payload: payload.into(),
+            timeout_ms,
+        }
+    }
+
+    /// Parse the dot-separated route.
+    pub fn route(&self) -> Result<Route> {
+        let addrs = self
+            .route
+            .split('.')
+            .map(|a| {
+                if a.is_empty() {
+                    return Err(ApiError::generic(&format!("Invalid route: {}", self.route)));
+                }
+                Address::from_str(a)
+                    .map_err(|_| ApiError::generic(&format!("Invalid address: {a}")))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Route::create(addrs))
+    }
+}
+
+///////////////////-!  RESPONSE BODIES
+
+/// Response body with the reply to a sent message
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct SendMessageResponse<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] tag: TypeTag<2094637>,
+    /// The reply, or `None` if none arrived in time
+    #[b(1)] pub reply: Option<CowBytes<'a>>,
+    #[n(2)] pub elapsed_ms: u32,
+}
+
+impl<'a> SendMessageResponse<'a> {
+    pub fn new(reply: Option<impl Into<CowBytes<'a>>>, elapsed_ms: u32) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            reply: reply.map(|r| r.into()),
+            elapsed_ms,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn payload() {
+        let payload: Vec<u8> = (0..100).collect();
+        let req = SendMessageRequest::new("app.echo", payload.as_slice(), 500);
+        let bytes = minicbor::to_vec(&req).unwrap();
+        let dec: SendMessageRequest = minicbor::decode(&bytes).unwrap();
+        assert_eq!(req, dec);
+        assert_eq!(payload.as_slice(), &*dec.payload);
+        assert!(dec.payload.is_borrowed());
+
+        let res = SendMessageResponse::new(Some(payload.as_slice()), 12);
+        let bytes = minicbor::to_vec(&res).unwrap();
+        assert_eq!(res, minicbor::decode(&bytes).unwrap());
+
+        let res = SendMessageResponse::new(None::<&[u8]>, 500);
+        let bytes = minicbor::to_vec(&res).unwrap();
+        assert_eq!(res, minicbor::decode(&bytes).unwrap())
+    }
+
+    #[test]
+    fn route() {
+        let req = SendMessageRequest::new("app.0#echo", Vec::new(), 0);
+        let route = req.route().unwrap();
+        let addrs: Vec<&Address> = route.iter().collect();
+        assert_eq!(
+            vec![&Address::from_string("app"), &Address::from_string("echo")],
+            addrs
+        );
+
+        for r in ["", "app..echo", "app."] {
+            assert!(SendMessageRequest::new(r, Vec::new(), 0).route().is_err())
+        }
+    }
+}
@@ -6,8 +6,15 @@ pub mod base;
 pub mod credentials;
 pub mod forwarder;
 pub mod identity;
+pub mod kafka;
+pub mod messages;
+pub mod node;
+pub mod policy;
 pub mod portal;
 pub mod secure_channel;
+pub mod secure_channel_listener;
 pub mod services;
 pub mod transport;
+pub mod trust_context;
 pub mod vault;
+pub mod workers;
@@ -0,0 +1,338 @@
+//! Node lifecycle API types
+
+use minicbor::encode::{self, Write};
+use minicbor::{decode, Decode, Decoder, Encode, Encoder};
+use serde::Serialize;
+
+use ockam_core::CowStr;
+#[cfg(feature = "tag")]
+use ockam_core::TypeTag;
+
+use crate::pagination::PaginatedResponse;
+
+///////////////////-!  REQUEST BODIES
+
+/// Request body when instructing a node manager to create a node
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct CreateNodeRequest<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] tag: TypeTag<2961443>,
+    #[b(1)] pub name: CowStr<'a>,
+    /// The vault the node should use, or the default one if `None`
+    #[b(2)] pub vault_name: Option<CowStr<'a>>,
+}
+
+impl<'a> CreateNodeRequest<'a> {
+    pub fn new(name: impl Into<CowStr<'a>>, vault_name: Option<impl Into<CowStr<'a>>>) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            name: name.into(),
+            vault_name: vault_name.map(|v| v.into()),
+        }
+    }
+}
+
+/// Request body when instructing a node manager to delete a node
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct DeleteNodeRequest<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] tag: TypeTag<5518230>,
+    #[b(1)] pub node_id: CowStr<'a>,
+}
+
+impl<'a> DeleteNodeRequest<'a> {
+    pub fn new(node_id: impl Into<CowStr<'a>>) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            node_id: node_id.into(),
+        }
+    }
+}
+
+///////////////////-!  RESPONSE BODIES
+
+/// Description of a single node
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct NodeInfo<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] tag: TypeTag<7730391>,
+    #[b(1)] pub name: CowStr<'a>,
+    #[b(2)] pub address: CowStr<'a>,
+    /// Creation time in seconds since the Unix epoch
+    #[n(3)] pub created_at: u64,
+    #[b(4)] pub status: NodeStatus<'a>,
+}
+
+impl<'a> NodeInfo<'a> {
+    pub fn new(
+        name: impl Into<CowStr<'a>>,
+        address: impl Into<CowStr<'a>>,
+        created_at: u64,
+        status: NodeStatus<'a>,
+    ) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            name: name.into(),
+            address: address.into(),
+            created_at,
+            status,
+        }
+    }
+}
+
+/// The lifecycle state of a node
+///
+/// Encoded as a map of `{0: discriminant, ?1: message}`, since the `Error`
+/// variant carries data and can not be encoded as a plain index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NodeStatus<'a> {
+    Running,
+    Stopping,
+    Stopped,
+    Error(CowStr<'a>),
+}
+
+impl NodeStatus<'_> {
+    /// Only a running node is considered healthy.
+    pub fn is_healthy(&self) -> bool {
+        matches!(self, NodeStatus::Running)
+    }
+
+    fn discriminant(&self) -> u8 {
+        match self {
+            NodeStatus::Running => 0,
+            NodeStatus::Stopping => 1,
+            NodeStatus::Stopped => 2,
+            NodeStatus::Error(_) => 3,
+        }
+    }
+}
+
+impl<C> Encode<C> for NodeStatus<'_> {
+    fn encode<W: Write>(
+        &self,
+        e: &mut Encoder<W>,
+        _: &mut C,
+    ) -> Result<(), encode::Error<W::Error>> {
+        if let NodeStatus::Error(msg) = self {
+            e.map(2)?.u8(0)?.u8(self.discriminant())?.u8(1)?.str(msg)?;
+        } else {
+            e.map(1)?.u8(0)?.u8(self.discriminant())?;
+        }
+        Ok(())
+    }
+}
+
+impl<'b, C> Decode<'b, C> for NodeStatus<'b> {
+    fn decode(d: &mut Decoder<'b>, _: &mut C) -> Result<Self, decode::Error> {
+        let len = d
+            .map()?
+            .ok_or_else(|| decode::Error::message("indefinite node status map"))?;
+        let mut discriminant = None;
+        let mut message = None;
+        for _ in 0..len {
+            match d.u8()? {
+                0 => discriminant = Some(d.u8()?),
+                1 => message = Some(d.str()?),
+                _ => d.skip()?,
+            }
+        }
+        match (discriminant, message) {
+            (Some(0), _) => Ok(NodeStatus::Running),
+            (Some(1), _) => Ok(NodeStatus::Stopping),
+            (Some(2), _) => Ok(NodeStatus::Stopped),
+            (Some(3), Some(msg)) => Ok(NodeStatus::Error(msg.into())),
+            (Some(3), None) => Err(decode::Error::message("node status error without message")),
+            (Some(n), _) => Err(decode::Error::message(format!("unknown node status {n}"))),
+            (None, _) => Err(decode::Error::message("missing node status discriminant")),
+        }
+    }
+}
+
+/// Response body after a node has been created
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct CreateNodeResponse<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] tag: TypeTag<1272054>,
+    #[b(1)] pub node: NodeInfo<'a>,
+}
+
+impl<'a> CreateNodeResponse<'a> {
+    pub fn new(node: NodeInfo<'a>) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            node,
+        }
+    }
+}
+
+/// Response body for a list of nodes
+pub type ListNodesResponse<'a> = PaginatedResponse<'a, NodeInfo<'a>>;
+
+/// Response body of a node health check, as used by liveness and
+/// readiness probes
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Serialize)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct HealthCheckResponse<'a> {
+    #[cfg(feature = "tag")]
+    #[serde(skip)]
+    #[n(0)] tag: TypeTag<4826193>,
+    #[n(1)] pub is_healthy: bool,
+    #[n(2)] pub uptime_secs: u64,
+    #[b(3)] pub node_name: CowStr<'a>,
+    /// The semver version of the node
+    #[b(4)] pub version: CowStr<'a>,
+}
+
+impl<'a> HealthCheckResponse<'a> {
+    pub fn builder(
+        node_name: impl Into<CowStr<'a>>,
+        version: impl Into<CowStr<'a>>,
+    ) -> HealthCheckResponseBuilder<'a> {
+        HealthCheckResponseBuilder {
+            inner: HealthCheckResponse {
+                #[cfg(feature = "tag")]
+                tag: TypeTag,
+                is_healthy: true,
+                uptime_secs: 0,
+                node_name: node_name.into(),
+                version: version.into(),
+            },
+        }
+    }
+}
+
+/// Builder of a [`HealthCheckResponse`]
+///
+/// A node is assumed to be healthy unless set otherwise.
+#[derive(Debug)]
+pub struct HealthCheckResponseBuilder<'a> {
+    inner: HealthCheckResponse<'a>,
+}
+
+impl<'a> HealthCheckResponseBuilder<'a> {
+    pub fn uptime_secs(mut self, secs: u64) -> Self {
+        self.inner.uptime_secs = secs;
+        self
+    }
+
+    pub fn is_healthy(mut self, healthy: bool) -> Self {
+        self.inner.is_healthy = healthy;
+        self
+    }
+
+    pub fn build(self) -> HealthCheckResponse<'a> {
+        self.inner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create_node_request() {
+        let req = CreateNodeRequest::new("n1", Some("v1"));
+        let bytes = minicbor::to_vec(&req).unwrap();
+        assert_eq!(req, minicbor::decode(&bytes).unwrap());
+
+        let req = CreateNodeRequest::new("n1", None::<&str>);
+        let bytes = minicbor::to_vec(&req).unwrap();
+        assert_eq!(req, minicbor::decode(&bytes).unwrap());
+    }
+
+    #[test]
+    fn delete_node_request() {
+        let req = DeleteNodeRequest::new("n1");
+        let bytes = minicbor::to_vec(&req).unwrap();
+        assert_eq!(req, minicbor::decode(&bytes).unwrap());
+    }
+
+    #[test]
+    fn create_node_response() {
+        let res = CreateNodeResponse::new(NodeInfo::new(
+            "n1",
+            "127.0.0.1:4000",
+            1663000000,
+            NodeStatus::Running,
+        ));
+        let bytes = minicbor::to_vec(&res).unwrap();
+        assert_eq!(res, minicbor::decode(&bytes).unwrap());
+    }
+
+    #[test]
+    fn list_nodes_response() {
+        let nodes = vec![
+            NodeInfo::new("n1", "127.0.0.1:4000", 1663000000, NodeStatus::Running),
+            NodeInfo::new(
+                "n2",
+                "127.0.0.1:4001",
+                1663000001,
+                NodeStatus::Error("crashed".into()),
+            ),
+        ];
+        let res = ListNodesResponse::new(nodes.clone(), Some("n2"));
+        let bytes = minicbor::to_vec(&res).unwrap();
+        assert_eq!(res, minicbor::decode(&bytes).unwrap());
+
+        let res = ListNodesResponse::new(nodes, None::<&str>);
+        let bytes = minicbor::to_vec(&res).unwrap();
+        assert_eq!(res, minicbor::decode(&bytes).unwrap());
+    }
+
+    #[test]
+    fn node_status() {
+        let all = [
+            NodeStatus::Running,
+            NodeStatus::Stopping,
+            NodeStatus::Stopped,
+            NodeStatus::Error("out of memory".into()),
+        ];
+        for status in all {
+            let bytes = minicbor::to_vec(&status).unwrap();
+            assert_eq!(status, minicbor::decode(&bytes).unwrap());
+            assert_eq!(status.is_healthy(), status == NodeStatus::Running)
+        }
+    }
+
+    #[test]
+    fn health_check_response() {
+        let res = HealthCheckResponse::builder("n1", "0.77.0")
+            .uptime_secs(3600)
+            .is_healthy(false)
+            .build();
+        let bytes = minicbor::to_vec(&res).unwrap();
+        let dec: HealthCheckResponse = minicbor::decode(&bytes).unwrap();
+        assert_eq!(res, dec);
+        assert!(!dec.is_healthy);
+        assert_eq!(3600, dec.uptime_secs);
+
+        let json = serde_json::to_value(&res).unwrap();
+        assert_eq!(
+            serde_json::json!({
+                "is_healthy": false,
+                "uptime_secs": 3600,
+                "node_name": "n1",
+                "version": "0.77.0"
+            }),
+            json
+        );
+
+        let res = HealthCheckResponse::builder("n1", "0.77.0").build();
+        assert!(res.is_healthy);
+        assert_eq!(0, res.uptime_secs)
+    }
+}
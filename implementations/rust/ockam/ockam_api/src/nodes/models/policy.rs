@@ -0,0 +1,211 @@
+//! Access control policy API types
+
+use minicbor::{Decode, Encode};
+
+use ockam_core::CowStr;
+#[cfg(feature = "tag")]
+use ockam_core::TypeTag;
+
+use crate::pagination::PaginatedResponse;
+
+/// The node manager path of the policy for an action on a resource
+pub fn policy_path(resource: &str, action: &str) -> String {
+    format!("/policy/{resource}/{action}")
+}
+
+/// The node path of the policy for an action on a resource, which
+/// accepts `GET`, `POST` and `DELETE` requests
+pub fn node_policy_path(resource: &str, action: &str) -> String {
+    format!("/node/policy/{resource}/{action}")
+}
+
+/// A boolean policy expression, e.g. `(= subject.role "admin")`.
+///
+/// The expression is kept verbatim, without trimming or normalisation.
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+#[cbor(transparent)]
+pub struct PolicyExpression<'a>(#[b(0)] CowStr<'a>);
+
+impl<'a> PolicyExpression<'a> {
+    pub fn new(expression: impl Into<CowStr<'a>>) -> Self {
+        Self(expression.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+///////////////////-!  REQUEST BODIES
+
+/// Request body to set the policy of an action on a resource
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct SetPolicyRequest<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] tag: TypeTag<4381922>,
+    #[b(1)] pub resource: CowStr<'a>,
+    #[b(2)] pub action: CowStr<'a>,
+    #[b(3)] pub expression: PolicyExpression<'a>,
+}
+
+impl<'a> SetPolicyRequest<'a> {
+    pub fn new(
+        resource: impl Into<CowStr<'a>>,
+        action: impl Into<CowStr<'a>>,
+        expression: PolicyExpression<'a>,
+    ) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            resource: resource.into(),
+            action: action.into(),
+            expression,
+        }
+    }
+}
+
+/// Request body to delete the policy of an action on a resource
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct DeletePolicyRequest<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] tag: TypeTag<7853160>,
+    #[b(1)] pub resource: CowStr<'a>,
+    #[b(2)] pub action: CowStr<'a>,
+}
+
+impl<'a> DeletePolicyRequest<'a> {
+    pub fn new(resource: impl Into<CowStr<'a>>, action: impl Into<CowStr<'a>>) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            resource: resource.into(),
+            action: action.into(),
+        }
+    }
+}
+
+///////////////////-!  RESPONSE BODIES
+
+/// Response body with the policy of an action on a resource
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct GetPolicyResponse<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] tag: TypeTag<5316607>,
+    #[b(1)] pub expression: PolicyExpression<'a>,
+}
+
+impl<'a> GetPolicyResponse<'a> {
+    pub fn new(expression: PolicyExpression<'a>) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            expression,
+        }
+    }
+}
+
+/// A policy of an action on a resource
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct NodePolicyResponse<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] tag: TypeTag<6120374>,
+    #[b(1)] pub resource: CowStr<'a>,
+    #[b(2)] pub action: CowStr<'a>,
+    #[b(3)] pub expression: PolicyExpression<'a>,
+}
+
+impl<'a> NodePolicyResponse<'a> {
+    pub fn new(
+        resource: impl Into<CowStr<'a>>,
+        action: impl Into<CowStr<'a>>,
+        expression: PolicyExpression<'a>,
+    ) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            resource: resource.into(),
+            action: action.into(),
+            expression,
+        }
+    }
+}
+
+impl<'a> From<SetPolicyRequest<'a>> for NodePolicyResponse<'a> {
+    fn from(req: SetPolicyRequest<'a>) -> Self {
+        NodePolicyResponse::new(req.resource, req.action, req.expression)
+    }
+}
+
+/// Response body for the policies of a node
+pub type ListNodePoliciesResponse<'a> = PaginatedResponse<'a, NodePolicyResponse<'a>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expression_is_preserved_verbatim() {
+        let expr = "  (and (= subject.role \"admin\")\n\t(= resource.project  \"p1\"))  ";
+        let req =
+            SetPolicyRequest::new("tcp-outlet", "handle_message", PolicyExpression::new(expr));
+        let bytes = minicbor::to_vec(&req).unwrap();
+        let dec: SetPolicyRequest = minicbor::decode(&bytes).unwrap();
+        assert_eq!(req, dec);
+        assert_eq!(expr, dec.expression.as_str());
+
+        let res = GetPolicyResponse::new(PolicyExpression::new(expr));
+        let bytes = minicbor::to_vec(&res).unwrap();
+        let dec: GetPolicyResponse = minicbor::decode(&bytes).unwrap();
+        assert_eq!(expr, dec.expression.as_str());
+    }
+
+    #[test]
+    fn delete_policy_request() {
+        let req = DeletePolicyRequest::new("tcp-outlet", "handle_message");
+        let bytes = minicbor::to_vec(&req).unwrap();
+        assert_eq!(req, minicbor::decode(&bytes).unwrap());
+    }
+
+    #[test]
+    fn list_node_policies_response() {
+        let exprs = [
+            r#"(= subject.name "a=b")"#,
+            r#"(or (= subject.role "\"admin\"") (= subject.id "(x)"))"#,
+            "(= resource.name \"tab\tand\nnewline\")",
+        ];
+        let policies: Vec<NodePolicyResponse> = exprs
+            .iter()
+            .map(|e| {
+                SetPolicyRequest::new("tcp-inlet", "handle_message", PolicyExpression::new(*e))
+            })
+            .map(NodePolicyResponse::from)
+            .collect();
+        let res = ListNodePoliciesResponse::complete(policies);
+        let bytes = minicbor::to_vec(&res).unwrap();
+        let dec: ListNodePoliciesResponse = minicbor::decode(&bytes).unwrap();
+        assert_eq!(res, dec);
+        for (p, e) in dec.items.iter().zip(exprs) {
+            assert_eq!(e, p.expression.as_str())
+        }
+    }
+
+    #[test]
+    fn path() {
+        assert_eq!(
+            "/policy/tcp-outlet/handle_message",
+            policy_path("tcp-outlet", "handle_message")
+        );
+        assert_eq!(
+            "/node/policy/tcp-outlet/handle_message",
+            node_policy_path("tcp-outlet", "handle_message")
+        );
+    }
+}
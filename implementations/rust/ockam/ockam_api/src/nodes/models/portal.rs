@@ -79,6 +79,75 @@ impl<'a> CreateOutlet<'a> {
     }
 }
 
+/// The state of an inlet or outlet
+#[derive(Copy, Clone, Debug, Decode, Encode, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(index_only)]
+pub enum PortalStatus {
+    #[n(0)] Up,
+    #[n(1)] Down,
+    #[n(2)] Starting,
+}
+
+/// Response body describing a single inlet
+#[derive(Clone, Debug, Decode, Encode, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct InletInfo<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] tag: TypeTag<2085163>,
+    #[b(1)] pub bind_addr: CowStr<'a>,
+    #[b(2)] pub outlet_route: CowStr<'a>,
+    #[b(3)] pub alias: Option<CowStr<'a>>,
+    #[n(4)] pub status: PortalStatus,
+}
+
+impl<'a> InletInfo<'a> {
+    pub fn new(
+        bind_addr: impl Into<CowStr<'a>>,
+        outlet_route: impl Into<CowStr<'a>>,
+        alias: impl Into<Option<CowStr<'a>>>,
+        status: PortalStatus,
+    ) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            bind_addr: bind_addr.into(),
+            outlet_route: outlet_route.into(),
+            alias: alias.into(),
+            status,
+        }
+    }
+}
+
+/// Response body describing a single outlet
+#[derive(Clone, Debug, Decode, Encode, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct OutletInfo<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] tag: TypeTag<6733418>,
+    #[b(1)] pub tcp_addr: CowStr<'a>,
+    #[b(2)] pub worker_addr: CowStr<'a>,
+    #[n(3)] pub status: PortalStatus,
+}
+
+impl<'a> OutletInfo<'a> {
+    pub fn new(
+        tcp_addr: impl Into<CowStr<'a>>,
+        worker_addr: impl Into<CowStr<'a>>,
+        status: PortalStatus,
+    ) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            tcp_addr: tcp_addr.into(),
+            worker_addr: worker_addr.into(),
+            status,
+        }
+    }
+}
+
 /// Response body when interacting with a portal endpoint
 #[derive(Clone, Debug, Decode, Encode)]
 #[rustfmt::skip]
@@ -208,3 +277,62 @@ impl<'a> OutletList<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create_inlet() {
+        let req = CreateInlet::new("127.0.0.1:5000", "/service/outlet", None, false);
+        let bytes = minicbor::to_vec(&req).unwrap();
+        let dec: CreateInlet = minicbor::decode(&bytes).unwrap();
+        assert_eq!(dec.bind_addr, "127.0.0.1:5000");
+        assert_eq!(dec.outlet_route, "/service/outlet");
+        assert!(dec.alias.is_none());
+
+        let req = CreateInlet::new("127.0.0.1:5000", "/service/outlet", Some("db".into()), true);
+        let bytes = minicbor::to_vec(&req).unwrap();
+        let dec: CreateInlet = minicbor::decode(&bytes).unwrap();
+        assert_eq!(dec.alias, Some("db".into()));
+        assert!(dec.check_credential);
+    }
+
+    #[test]
+    fn create_outlet() {
+        let req = CreateOutlet::new("127.0.0.1:5432", "outlet", None, false);
+        let bytes = minicbor::to_vec(&req).unwrap();
+        let dec: CreateOutlet = minicbor::decode(&bytes).unwrap();
+        assert_eq!(dec.tcp_addr, "127.0.0.1:5432");
+        assert_eq!(dec.worker_addr, "outlet");
+        assert!(dec.alias.is_none());
+    }
+
+    #[test]
+    fn inlet_info() {
+        for status in [PortalStatus::Up, PortalStatus::Down, PortalStatus::Starting] {
+            let info = InletInfo::new("127.0.0.1:5000", "/service/outlet", None, status);
+            let bytes = minicbor::to_vec(&info).unwrap();
+            let dec: InletInfo = minicbor::decode(&bytes).unwrap();
+            assert_eq!(info, dec);
+            assert!(dec.alias.is_none());
+        }
+        let info = InletInfo::new(
+            "127.0.0.1:5000",
+            "/service/outlet",
+            Some("db".into()),
+            PortalStatus::Up,
+        );
+        let bytes = minicbor::to_vec(&info).unwrap();
+        assert_eq!(info, minicbor::decode(&bytes).unwrap());
+    }
+
+    #[test]
+    fn outlet_info() {
+        for status in [PortalStatus::Up, PortalStatus::Down, PortalStatus::Starting] {
+            let info = OutletInfo::new("127.0.0.1:5432", "outlet", status);
+            let bytes = minicbor::to_vec(&info).unwrap();
+            assert_eq!(info, minicbor::decode(&bytes).unwrap());
+        }
+    }
+}
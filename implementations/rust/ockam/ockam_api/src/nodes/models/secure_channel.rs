@@ -2,7 +2,7 @@ use std::time::Duration;
 
 use minicbor::{Decode, Encode};
 
-use crate::nodes::registry::SecureChannelInfo;
+use crate::nodes::registry;
 use ockam_core::compat::borrow::Cow;
 #[cfg(feature = "tag")]
 use ockam_core::TypeTag;
@@ -12,6 +12,7 @@ use ockam_multiaddr::MultiAddr;
 use serde::Serialize;
 
 use crate::error::ApiError;
+use crate::pagination::PaginatedResponse;
 use crate::route_to_multiaddr;
 
 #[derive(Debug, Clone, Copy, Decode, Encode)]
@@ -87,28 +88,6 @@ impl<'a> CreateSecureChannelResponse<'a> {
     }
 }
 
-/// Request body when instructing a node to create a Secure Channel Listener
-#[derive(Debug, Clone, Decode, Encode)]
-#[rustfmt::skip]
-#[cbor(map)]
-pub struct CreateSecureChannelListenerRequest<'a> {
-    #[cfg(feature = "tag")]
-    #[n(0)] tag: TypeTag<8112242>,
-    #[b(1)] pub addr: Cow<'a, str>,
-    #[b(2)] pub authorized_identifiers: Option<Vec<CowStr<'a>>>,
-}
-
-impl<'a> CreateSecureChannelListenerRequest<'a> {
-    pub fn new(addr: &Address, authorized_identifiers: Option<Vec<IdentityIdentifier>>) -> Self {
-        Self {
-            #[cfg(feature = "tag")]
-            tag: TypeTag,
-            addr: addr.to_string().into(),
-            authorized_identifiers: authorized_identifiers
-                .map(|x| x.into_iter().map(|y| y.to_string().into()).collect()),
-        }
-    }
-}
 #[derive(Debug, Clone, Decode, Encode)]
 #[rustfmt::skip]
 #[cbor(map)]
@@ -178,7 +157,7 @@ pub struct ShowSecureChannelResponse<'a> {
 }
 
 impl<'a> ShowSecureChannelResponse<'a> {
-    pub fn new(info: Option<&SecureChannelInfo>) -> Self {
+    pub fn new(info: Option<&registry::SecureChannelInfo>) -> Self {
         Self {
             #[cfg(feature = "tag")]
             tag: TypeTag,
@@ -193,3 +172,68 @@ impl<'a> ShowSecureChannelResponse<'a> {
         }
     }
 }
+
+/// Description of an established secure channel
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct SecureChannelInfo<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] tag: TypeTag<4127359>,
+    /// Local address of the channel
+    #[b(1)] pub addr: CowStr<'a>,
+    /// Route to the remote end of the channel
+    #[b(2)] pub route: CowStr<'a>,
+    /// Creation time in seconds since the Unix epoch
+    #[n(3)] pub created_at: u64,
+}
+
+impl<'a> SecureChannelInfo<'a> {
+    pub fn new(addr: &Address, route: &MultiAddr, created_at: u64) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            addr: addr.to_string().into(),
+            route: route.to_string().into(),
+            created_at,
+        }
+    }
+}
+
+/// Response body for a list of secure channels
+pub type ListSecureChannelsResponse<'a> = PaginatedResponse<'a, SecureChannelInfo<'a>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create_secure_channel_request() {
+        let addr: MultiAddr = "/dnsaddr/localhost/tcp/4000/service/api".parse().unwrap();
+        let req = CreateSecureChannelRequest::new(&addr, None, CredentialExchangeMode::Mutual);
+        let bytes = minicbor::to_vec(&req).unwrap();
+        let dec: CreateSecureChannelRequest = minicbor::decode(&bytes).unwrap();
+        assert_eq!(req.addr, dec.addr);
+        assert_eq!(dec.addr.parse::<MultiAddr>().unwrap(), addr);
+        assert!(dec.authorized_identifiers.is_none());
+    }
+
+    #[test]
+    fn delete_secure_channel_request() {
+        let req = DeleteSecureChannelRequest::new(&Address::from_string("sc1"));
+        let bytes = minicbor::to_vec(&req).unwrap();
+        let dec: DeleteSecureChannelRequest = minicbor::decode(&bytes).unwrap();
+        assert_eq!(req.channel, dec.channel);
+    }
+
+    #[test]
+    fn list_secure_channels_response() {
+        let route: MultiAddr = "/dnsaddr/localhost/tcp/4000/service/api".parse().unwrap();
+        let res = ListSecureChannelsResponse::complete(vec![
+            SecureChannelInfo::new(&Address::from_string("sc1"), &route, 1663000000),
+            SecureChannelInfo::new(&Address::from_string("sc2"), &route, 1663000001),
+        ]);
+        let bytes = minicbor::to_vec(&res).unwrap();
+        assert_eq!(res, minicbor::decode(&bytes).unwrap());
+    }
+}
@@ -0,0 +1,174 @@
+use minicbor::{Decode, Encode};
+
+#[cfg(feature = "tag")]
+use ockam_core::TypeTag;
+use ockam_core::{Address, CowStr};
+use ockam_identity::IdentityIdentifier;
+
+use crate::pagination::PaginatedResponse;
+
+///////////////////-!  REQUEST BODIES
+
+/// Request body when instructing a node to create a Secure Channel Listener
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct CreateSecureChannelListenerRequest<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] tag: TypeTag<8112242>,
+    /// The worker address of the listener
+    #[b(1)] pub address: CowStr<'a>,
+    /// The identifiers allowed to connect; if absent, any identity is
+    /// accepted, if empty, none is
+    #[b(2)] pub authorized_identifiers: Option<Vec<CowStr<'a>>>,
+    /// The vault holding the listener's keys; the node's vault if absent
+    #[b(3)] pub vault_name: Option<CowStr<'a>>,
+}
+
+impl<'a> CreateSecureChannelListenerRequest<'a> {
+    pub fn new(address: &Address, authorized_identifiers: Option<Vec<IdentityIdentifier>>) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            address: address.to_string().into(),
+            authorized_identifiers: authorized_identifiers
+                .map(|x| x.into_iter().map(|y| y.to_string().into()).collect()),
+            vault_name: None,
+        }
+    }
+
+    pub fn with_vault_name(mut self, vault_name: impl Into<CowStr<'a>>) -> Self {
+        self.vault_name = Some(vault_name.into());
+        self
+    }
+}
+
+/// Request body when instructing a node to delete a Secure Channel Listener
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct DeleteSecureChannelListenerRequest<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] tag: TypeTag<3518406>,
+    #[b(1)] pub address: CowStr<'a>,
+}
+
+impl<'a> DeleteSecureChannelListenerRequest<'a> {
+    pub fn new(address: &Address) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            address: address.to_string().into(),
+        }
+    }
+}
+
+///////////////////-!  RESPONSE BODIES
+
+/// Description of a Secure Channel Listener
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct SecureChannelListenerInfo<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] tag: TypeTag<7290143>,
+    #[b(1)] pub address: CowStr<'a>,
+    /// The identifiers allowed to connect; if absent, any identity is
+    /// accepted, if empty, none is
+    #[b(2)] pub authorized_identifiers: Option<Vec<CowStr<'a>>>,
+    #[n(3)] pub is_listening: bool,
+}
+
+impl<'a> SecureChannelListenerInfo<'a> {
+    pub fn new(
+        address: &Address,
+        authorized_identifiers: Option<&[IdentityIdentifier]>,
+        is_listening: bool,
+    ) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            address: address.to_string().into(),
+            authorized_identifiers: authorized_identifiers
+                .map(|x| x.iter().map(|y| y.to_string().into()).collect()),
+            is_listening,
+        }
+    }
+}
+
+/// Response body for a list of Secure Channel Listeners
+pub type ListSecureChannelListenersResponse<'a> =
+    PaginatedResponse<'a, SecureChannelListenerInfo<'a>>;
+
+#[cfg(test)]
+mod tests {
+    use minicbor::data::Type;
+    use minicbor::Decoder;
+
+    use super::*;
+
+    /// Find the value of the given key in an encoded map.
+    fn field<'b>(bytes: &'b [u8], key: u8) -> Option<Decoder<'b>> {
+        let mut d = Decoder::new(bytes);
+        let len = d.map().unwrap().unwrap();
+        for _ in 0..len {
+            if d.u8().unwrap() == key {
+                return Some(d);
+            }
+            d.skip().unwrap()
+        }
+        None
+    }
+
+    #[test]
+    fn authorized_identifiers() {
+        let addr = Address::from_string("api");
+
+        // No list means that any identity is accepted:
+        let req = CreateSecureChannelListenerRequest::new(&addr, None);
+        let bytes = minicbor::to_vec(&req).unwrap();
+        let dec: CreateSecureChannelListenerRequest = minicbor::decode(&bytes).unwrap();
+        assert_eq!(req, dec);
+        assert!(dec.authorized_identifiers.is_none());
+        assert!(field(&bytes, 2).is_none());
+
+        // An empty list means that no identity is accepted:
+        let req = CreateSecureChannelListenerRequest::new(&addr, Some(vec![]));
+        let bytes = minicbor::to_vec(&req).unwrap();
+        let dec: CreateSecureChannelListenerRequest = minicbor::decode(&bytes).unwrap();
+        assert_eq!(req, dec);
+        assert_eq!(Some(vec![]), dec.authorized_identifiers);
+        let mut d = field(&bytes, 2).unwrap();
+        assert_eq!(Type::Array, d.datatype().unwrap());
+        assert_eq!(Some(0), d.array().unwrap());
+
+        for ids in [None, Some(&[][..])] {
+            let info = SecureChannelListenerInfo::new(&addr, ids, true);
+            let bytes = minicbor::to_vec(&info).unwrap();
+            let dec: SecureChannelListenerInfo = minicbor::decode(&bytes).unwrap();
+            assert_eq!(info, dec);
+            assert_eq!(ids.is_some(), dec.authorized_identifiers.is_some())
+        }
+    }
+
+    #[test]
+    fn roundtrip() {
+        let id = IdentityIdentifier::from_key_id("6474cfdbf547240b6d716bff89c97681");
+        let addr = Address::from_string("listener");
+        let req = CreateSecureChannelListenerRequest::new(&addr, Some(vec![id.clone()]))
+            .with_vault_name("v1");
+        let bytes = minicbor::to_vec(&req).unwrap();
+        assert_eq!(req, minicbor::decode(&bytes).unwrap());
+
+        let req = DeleteSecureChannelListenerRequest::new(&addr);
+        let bytes = minicbor::to_vec(&req).unwrap();
+        assert_eq!(req, minicbor::decode(&bytes).unwrap());
+
+        let res = ListSecureChannelListenersResponse::complete(vec![
+            SecureChannelListenerInfo::new(&addr, Some(&[id]), true),
+            SecureChannelListenerInfo::new(&Address::from_string("api"), None, true),
+        ]);
+        let bytes = minicbor::to_vec(&res).unwrap();
+        assert_eq!(res, minicbor::decode(&bytes).unwrap());
+    }
+}
@@ -1,11 +1,15 @@
+use std::fmt;
 use std::path::Path;
 
 use minicbor::{bytes::ByteSlice, Decode, Encode};
 use ockam_core::compat::borrow::Cow;
+use ockam_core::CowStr;
 
 #[cfg(feature = "tag")]
 use ockam_core::TypeTag;
 
+use crate::pagination::PaginatedResponse;
+
 /// Request body when instructing a node to start a Vault service
 #[derive(Debug, Clone, Decode, Encode)]
 #[rustfmt::skip]
@@ -193,43 +197,92 @@ impl<'a> StartCredentialsService<'a> {
     }
 }
 
-#[derive(Debug, Clone, Decode, Encode)]
+/// The kind of service running on a node
+#[derive(Copy, Clone, Debug, Decode, Encode, PartialEq, Eq)]
 #[rustfmt::skip]
-#[cbor(map)]
-pub struct ServiceStatus<'a> {
-    #[cfg(feature = "tag")]
-    #[n(0)] tag: TypeTag<8542064>,
-    #[n(2)] pub addr: Cow<'a, str>,
-    #[n(3)] pub service_type: Cow<'a, str>,
+#[cbor(index_only)]
+pub enum ServiceType {
+    #[n(0)] Echo,
+    #[n(1)] Vault,
+    #[n(2)] Identity,
+    #[n(3)] Credentials,
+    #[n(4)] Authenticator,
+    #[n(5)] Verifier,
+    #[n(6)] Uppercase,
+    #[n(7)] Other,
 }
 
-impl<'a> ServiceStatus<'a> {
-    pub fn new(addr: impl Into<Cow<'a, str>>, service_type: impl Into<Cow<'a, str>>) -> Self {
-        Self {
-            #[cfg(feature = "tag")]
-            tag: TypeTag,
-            addr: addr.into(),
-            service_type: service_type.into(),
-        }
+impl fmt::Display for ServiceType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Echo => "echo",
+            Self::Vault => "vault",
+            Self::Identity => "identity",
+            Self::Credentials => "credentials",
+            Self::Authenticator => "authenticator",
+            Self::Verifier => "verifier",
+            Self::Uppercase => "uppercase",
+            Self::Other => "other",
+        })
     }
 }
 
-/// Response body for listing services
-#[derive(Debug, Clone, Decode, Encode)]
+/// Response body describing a single service
+#[derive(Debug, Clone, Decode, Encode, PartialEq, Eq)]
 #[rustfmt::skip]
 #[cbor(map)]
-pub struct ServiceList<'a> {
+pub struct ServiceInfo<'a> {
     #[cfg(feature = "tag")]
-    #[n(0)] tag: TypeTag<9587601>,
-    #[n(1)] pub list: Vec<ServiceStatus<'a>>
+    #[n(0)] tag: TypeTag<8542064>,
+    #[b(1)] pub address: CowStr<'a>,
+    #[n(2)] pub service_type: ServiceType,
+    #[n(3)] pub is_available: bool,
 }
 
-impl<'a> ServiceList<'a> {
-    pub fn new(list: Vec<ServiceStatus<'a>>) -> Self {
+impl<'a> ServiceInfo<'a> {
+    pub fn new(
+        address: impl Into<CowStr<'a>>,
+        service_type: ServiceType,
+        is_available: bool,
+    ) -> Self {
         Self {
             #[cfg(feature = "tag")]
             tag: TypeTag,
-            list,
+            address: address.into(),
+            service_type,
+            is_available,
         }
     }
 }
+
+/// Response body for listing services
+pub type ListServicesResponse<'a> = PaginatedResponse<'a, ServiceInfo<'a>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn list_services_response() {
+        use ServiceType::*;
+        let services = [
+            Echo,
+            Vault,
+            Identity,
+            Credentials,
+            Authenticator,
+            Verifier,
+            Uppercase,
+            Other,
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(i, t)| ServiceInfo::new(format!("service.{i}"), t, i % 2 == 0))
+        .collect();
+        let res = ListServicesResponse::complete(services);
+        let bytes = minicbor::to_vec(&res).unwrap();
+        let dec: ListServicesResponse = minicbor::decode(&bytes).unwrap();
+        assert_eq!(res, dec);
+        assert_eq!(dec.items[4].service_type, Authenticator);
+    }
+}
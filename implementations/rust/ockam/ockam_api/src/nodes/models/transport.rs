@@ -1,7 +1,10 @@
 use minicbor::{Decode, Encode};
 use ockam_core::compat::borrow::Cow;
+use ockam_core::CowStr;
 use std::fmt::{self, Display};
 
+use crate::pagination::PaginatedResponse;
+
 #[cfg(feature = "tag")]
 use ockam_core::TypeTag;
 
@@ -104,6 +107,76 @@ impl Display for TransportMode {
     }
 }
 
+/// Request body when instructing a node to open a TCP connection
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct CreateTcpConnectionRequest<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] tag: TypeTag<3360217>,
+    /// The remote peer, as `hostname:port`
+    #[b(1)] pub peer: CowStr<'a>,
+    /// A user-chosen name for the connection
+    #[b(2)] pub alias: Option<CowStr<'a>>,
+    #[n(3)] pub allow_insecure: bool,
+}
+
+impl<'a> CreateTcpConnectionRequest<'a> {
+    pub fn new(
+        peer: impl Into<CowStr<'a>>,
+        alias: Option<impl Into<CowStr<'a>>>,
+        allow_insecure: bool,
+    ) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            peer: peer.into(),
+            alias: alias.map(|a| a.into()),
+            allow_insecure,
+        }
+    }
+}
+
+/// Request body when instructing a node to listen for TCP connections
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct CreateTcpListenerRequest<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] tag: TypeTag<6127094>,
+    /// The socket address to bind to, as `ip:port`
+    #[b(1)] pub bind_addr: CowStr<'a>,
+}
+
+impl<'a> CreateTcpListenerRequest<'a> {
+    pub fn new(bind_addr: impl Into<CowStr<'a>>) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            bind_addr: bind_addr.into(),
+        }
+    }
+}
+
+/// Which side initiated a TCP connection
+#[derive(Copy, Clone, Debug, Decode, Encode, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(index_only)]
+pub enum ConnectionDirection {
+    #[n(0)] Incoming,
+    #[n(1)] Outgoing,
+}
+
+/// The state of a TCP connection
+#[derive(Copy, Clone, Debug, Decode, Encode, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(index_only)]
+pub enum ConnectionStatus {
+    #[n(0)] Connected,
+    #[n(1)] Disconnected,
+    #[n(2)] Connecting,
+}
+
 ///////////////////-!  RESPONSE BODIES
 
 /// Respons body when interacting with a transport
@@ -163,3 +236,96 @@ impl<'a> TransportList<'a> {
         }
     }
 }
+
+/// Response body describing a single TCP connection
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct TcpConnectionInfo<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] tag: TypeTag<9542071>,
+    /// Transport ID inside the node manager
+    #[b(1)] pub id: CowStr<'a>,
+    #[b(2)] pub peer: CowStr<'a>,
+    #[n(3)] pub direction: ConnectionDirection,
+    #[n(4)] pub status: ConnectionStatus,
+}
+
+impl<'a> TcpConnectionInfo<'a> {
+    pub fn new(
+        id: impl Into<CowStr<'a>>,
+        peer: impl Into<CowStr<'a>>,
+        direction: ConnectionDirection,
+        status: ConnectionStatus,
+    ) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            id: id.into(),
+            peer: peer.into(),
+            direction,
+            status,
+        }
+    }
+}
+
+/// Response body for a list of TCP connections
+pub type ListTcpConnectionsResponse<'a> = PaginatedResponse<'a, TcpConnectionInfo<'a>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create_tcp_connection_request() {
+        let req = CreateTcpConnectionRequest::new("localhost:4000", Some("cloud"), false);
+        let bytes = minicbor::to_vec(&req).unwrap();
+        assert_eq!(req, minicbor::decode(&bytes).unwrap());
+
+        let req = CreateTcpConnectionRequest::new("localhost:4000", None::<&str>, true);
+        let bytes = minicbor::to_vec(&req).unwrap();
+        assert_eq!(req, minicbor::decode(&bytes).unwrap());
+    }
+
+    #[test]
+    fn create_tcp_listener_request() {
+        let req = CreateTcpListenerRequest::new("127.0.0.1:4000");
+        let bytes = minicbor::to_vec(&req).unwrap();
+        assert_eq!(req, minicbor::decode(&bytes).unwrap());
+    }
+
+    #[test]
+    fn tcp_connection_info() {
+        use ConnectionDirection::*;
+        use ConnectionStatus::*;
+        for direction in [Incoming, Outgoing] {
+            for status in [Connected, Disconnected, Connecting] {
+                let info = TcpConnectionInfo::new("c1", "localhost:4000", direction, status);
+                let bytes = minicbor::to_vec(&info).unwrap();
+                assert_eq!(info, minicbor::decode(&bytes).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn list_tcp_connections_response() {
+        let res = ListTcpConnectionsResponse::complete(vec![
+            TcpConnectionInfo::new(
+                "c1",
+                "localhost:4000",
+                ConnectionDirection::Outgoing,
+                ConnectionStatus::Connected,
+            ),
+            TcpConnectionInfo::new(
+                "c2",
+                "127.0.0.1:5000",
+                ConnectionDirection::Incoming,
+                ConnectionStatus::Disconnected,
+            ),
+        ]);
+        let bytes = minicbor::to_vec(&res).unwrap();
+        let dec: ListTcpConnectionsResponse = minicbor::decode(&bytes).unwrap();
+        assert_eq!(res, dec);
+        assert_eq!(2, dec.items.len())
+    }
+}
@@ -0,0 +1,113 @@
+//! Trust context API types
+
+use minicbor::{Decode, Encode};
+
+use ockam_core::CowStr;
+#[cfg(feature = "tag")]
+use ockam_core::TypeTag;
+
+use crate::pagination::PaginatedResponse;
+
+///////////////////-!  REQUEST BODIES
+
+/// Request body to create a trust context
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct CreateTrustContextRequest<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] tag: TypeTag<4472058>,
+    #[b(1)] pub id: CowStr<'a>,
+    #[b(2)] pub credential_authority: Option<CowStr<'a>>,
+    #[b(3)] pub credential_retriever: Option<CowStr<'a>>,
+}
+
+impl<'a> CreateTrustContextRequest<'a> {
+    pub fn new(id: impl Into<CowStr<'a>>) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            id: id.into(),
+            credential_authority: None,
+            credential_retriever: None,
+        }
+    }
+
+    pub fn with_credential_authority(mut self, identity: impl Into<CowStr<'a>>) -> Self {
+        self.credential_authority = Some(identity.into());
+        self
+    }
+
+    pub fn with_credential_retriever(mut self, route: impl Into<CowStr<'a>>) -> Self {
+        self.credential_retriever = Some(route.into());
+        self
+    }
+}
+
+///////////////////-!  RESPONSE BODIES
+
+/// Description of a trust context
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct TrustContextInfo<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] tag: TypeTag<6631917>,
+    #[b(1)] pub id: CowStr<'a>,
+    /// The identity of the credential authority
+    #[b(2)] pub credential_authority: Option<CowStr<'a>>,
+    /// The route to retrieve credentials from
+    #[b(3)] pub credential_retriever: Option<CowStr<'a>>,
+    #[n(4)] pub is_default: bool,
+}
+
+impl<'a> TrustContextInfo<'a> {
+    pub fn new(req: CreateTrustContextRequest<'a>, is_default: bool) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            id: req.id,
+            credential_authority: req.credential_authority,
+            credential_retriever: req.credential_retriever,
+            is_default,
+        }
+    }
+
+    pub fn has_authority(&self) -> bool {
+        self.credential_authority.is_some()
+    }
+}
+
+/// Response body for a list of trust contexts
+pub type ListTrustContextsResponse<'a> = PaginatedResponse<'a, TrustContextInfo<'a>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn optional_fields() {
+        let min = TrustContextInfo::new(CreateTrustContextRequest::new("tc1"), false);
+        let req = CreateTrustContextRequest::new("tc1")
+            .with_credential_authority("P6c20e814b56579306f55c64e8747e6c1")
+            .with_credential_retriever("/dnsaddr/authority/tcp/4000/service/credentials");
+        let bytes = minicbor::to_vec(&req).unwrap();
+        assert_eq!(req, minicbor::decode(&bytes).unwrap());
+        let max = TrustContextInfo::new(req, true);
+
+        let min_bytes = minicbor::to_vec(&min).unwrap();
+        let max_bytes = minicbor::to_vec(&max).unwrap();
+        assert!(min_bytes.len() < max_bytes.len());
+
+        let dec: TrustContextInfo = minicbor::decode(&min_bytes).unwrap();
+        assert_eq!(min, dec);
+        assert!(!dec.has_authority());
+        let dec: TrustContextInfo = minicbor::decode(&max_bytes).unwrap();
+        assert_eq!(max, dec);
+        assert!(dec.has_authority());
+
+        let res = ListTrustContextsResponse::complete(vec![min, max]);
+        let bytes = minicbor::to_vec(&res).unwrap();
+        assert_eq!(res, minicbor::decode(&bytes).unwrap())
+    }
+}
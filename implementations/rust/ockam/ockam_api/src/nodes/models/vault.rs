@@ -1,8 +1,19 @@
 use minicbor::{Decode, Encode};
 
-use ockam_core::CowStr;
 #[cfg(feature = "tag")]
 use ockam_core::TypeTag;
+use ockam_core::{CowBytes, CowStr};
+
+/// The kind of storage backing a vault
+#[derive(Copy, Clone, Debug, Decode, Encode, PartialEq, Eq)]
+#[rustfmt::skip]
+#[cbor(index_only)]
+pub enum VaultType {
+    /// Keys are kept in a local file
+    #[n(0)] Software,
+    /// Keys are kept in AWS KMS
+    #[n(1)] Aws,
+}
 
 /// Request body when instructing a node to create a Vault
 #[derive(Debug, Clone, Decode, Encode)]
@@ -11,15 +22,233 @@ use ockam_core::TypeTag;
 pub struct CreateVaultRequest<'a> {
     #[cfg(feature = "tag")]
     #[n(0)] tag: TypeTag<8008758>,
+    /// Storage file of a software vault
     #[b(1)] pub path: Option<CowStr<'a>>,
+    #[b(2)] pub name: CowStr<'a>,
+    #[n(3)] pub vault_type: VaultType,
 }
 
 impl<'a> CreateVaultRequest<'a> {
-    pub fn new(path: Option<impl Into<CowStr<'a>>>) -> Self {
+    pub fn new(
+        name: impl Into<CowStr<'a>>,
+        path: Option<impl Into<CowStr<'a>>>,
+        vault_type: VaultType,
+    ) -> Self {
         Self {
             #[cfg(feature = "tag")]
             tag: TypeTag,
             path: path.map(|p| p.into()),
+            name: name.into(),
+            vault_type,
+        }
+    }
+}
+
+/// Request body when instructing a node to delete a Vault
+#[derive(Debug, Clone, Decode, Encode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct DeleteVaultRequest<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] tag: TypeTag<3594116>,
+    #[b(1)] pub name: CowStr<'a>,
+}
+
+impl<'a> DeleteVaultRequest<'a> {
+    pub fn new(name: impl Into<CowStr<'a>>) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            name: name.into(),
+        }
+    }
+}
+
+/// Response body describing a Vault
+#[derive(Debug, Clone, Decode, Encode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct VaultInfo<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] tag: TypeTag<5827390>,
+    #[b(1)] pub name: CowStr<'a>,
+    #[n(2)] pub is_default: bool,
+    #[n(3)] pub vault_type: VaultType,
+}
+
+impl<'a> VaultInfo<'a> {
+    pub fn new(name: impl Into<CowStr<'a>>, is_default: bool, vault_type: VaultType) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            name: name.into(),
+            is_default,
+            vault_type,
+        }
+    }
+}
+
+/// Request body to sign data with a key of a vault (`POST /vault/sign`)
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct SignRequest<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] tag: TypeTag<1409837>,
+    #[b(1)] pub vault_name: CowStr<'a>,
+    #[b(2)] pub key_label: CowStr<'a>,
+    #[b(3)] pub data: CowBytes<'a>,
+}
+
+impl<'a> SignRequest<'a> {
+    pub fn new(
+        vault_name: impl Into<CowStr<'a>>,
+        key_label: impl Into<CowStr<'a>>,
+        data: impl Into<CowBytes<'a>>,
+    ) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            vault_name: vault_name.into(),
+            key_label: key_label.into(),
+            data: data.into(),
+        }
+    }
+}
+
+/// Response body with a signature
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct SignResponse<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] tag: TypeTag<7260564>,
+    #[b(1)] pub signature: CowBytes<'a>,
+}
+
+impl<'a> SignResponse<'a> {
+    pub fn new(signature: impl Into<CowBytes<'a>>) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            signature: signature.into(),
+        }
+    }
+}
+
+/// Request body to verify a signature with a key of a vault (`POST /vault/verify`)
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct VerifyRequest<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] tag: TypeTag<3918226>,
+    #[b(1)] pub vault_name: CowStr<'a>,
+    #[b(2)] pub key_label: CowStr<'a>,
+    #[b(3)] pub data: CowBytes<'a>,
+    #[b(4)] pub signature: CowBytes<'a>,
+}
+
+impl<'a> VerifyRequest<'a> {
+    pub fn new(
+        vault_name: impl Into<CowStr<'a>>,
+        key_label: impl Into<CowStr<'a>>,
+        data: impl Into<CowBytes<'a>>,
+        signature: impl Into<CowBytes<'a>>,
+    ) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            vault_name: vault_name.into(),
+            key_label: key_label.into(),
+            data: data.into(),
+            signature: signature.into(),
+        }
+    }
+}
+
+/// Response body with the result of a signature verification
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct VerifyResponse {
+    #[cfg(feature = "tag")]
+    #[n(0)] tag: TypeTag<6053791>,
+    #[n(1)] pub is_valid: bool,
+}
+
+impl VerifyResponse {
+    pub fn new(is_valid: bool) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            is_valid,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vault_type() {
+        for vault_type in [VaultType::Software, VaultType::Aws] {
+            let info = VaultInfo::new("v1", true, vault_type);
+            let bytes = minicbor::to_vec(&info).unwrap();
+            let dec: VaultInfo = minicbor::decode(&bytes).unwrap();
+            assert_eq!(vault_type, dec.vault_type);
+        }
+    }
+
+    #[test]
+    fn create_vault_request_without_path() {
+        let with = CreateVaultRequest::new("v1", Some("/tmp/vault"), VaultType::Software);
+        let without = CreateVaultRequest::new("v1", None::<&str>, VaultType::Software);
+        let with = minicbor::to_vec(&with).unwrap();
+        let without = minicbor::to_vec(&without).unwrap();
+        assert!(without.len() < with.len());
+
+        let dec: CreateVaultRequest = minicbor::decode(&without).unwrap();
+        assert!(dec.path.is_none());
+        assert_eq!(dec.name, "v1");
+    }
+
+    #[test]
+    fn sign_and_verify() {
+        let data: Vec<u8> = (0..=255).collect();
+        let signature = [0u8, 0xff, 0, 0x80, 0x7f, 0];
+
+        let req = SignRequest::new("v1", "k1", data.as_slice());
+        let bytes = minicbor::to_vec(&req).unwrap();
+        let dec: SignRequest = minicbor::decode(&bytes).unwrap();
+        assert_eq!(req, dec);
+        assert_eq!(data, &*dec.data);
+
+        let res = SignResponse::new(&signature[..]);
+        let bytes = minicbor::to_vec(&res).unwrap();
+        let dec: SignResponse = minicbor::decode(&bytes).unwrap();
+        assert_eq!(signature, &*dec.signature);
+
+        let req = VerifyRequest::new("v1", "k1", data.as_slice(), &signature[..]);
+        let bytes = minicbor::to_vec(&req).unwrap();
+        let dec: VerifyRequest = minicbor::decode(&bytes).unwrap();
+        assert_eq!(req, dec);
+        assert_eq!(data, &*dec.data);
+        assert_eq!(signature, &*dec.signature);
+    }
+
+    #[test]
+    fn verify_response() {
+        for is_valid in [true, false] {
+            let bytes = minicbor::to_vec(VerifyResponse::new(is_valid)).unwrap();
+            let dec: VerifyResponse = minicbor::decode(&bytes).unwrap();
+            assert_eq!(is_valid, dec.is_valid);
         }
+        // `false` is encoded explicitly rather than omitted:
+        let valid = minicbor::to_vec(VerifyResponse::new(true)).unwrap();
+        let invalid = minicbor::to_vec(VerifyResponse::new(false)).unwrap();
+        assert_eq!(valid.len(), invalid.len());
+        assert_ne!(valid, invalid)
     }
 }
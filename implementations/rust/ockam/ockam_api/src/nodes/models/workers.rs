@@ -0,0 +1,76 @@
+//! Worker management API types
+
+use minicbor::{Decode, Encode};
+
+use ockam_core::CowStr;
+#[cfg(feature = "tag")]
+use ockam_core::TypeTag;
+
+use crate::pagination::PaginatedResponse;
+
+/// Description of a worker running on a node
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct WorkerInfo<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] tag: TypeTag<4416597>,
+    #[b(1)] pub address: CowStr<'a>,
+    #[n(2)] pub is_paused: bool,
+    /// A human-readable description of the worker
+    #[b(3)] pub metadata: Option<CowStr<'a>>,
+}
+
+impl<'a> WorkerInfo<'a> {
+    pub fn new(
+        address: impl Into<CowStr<'a>>,
+        is_paused: bool,
+        metadata: Option<impl Into<CowStr<'a>>>,
+    ) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            address: address.into(),
+            is_paused,
+            metadata: metadata.map(|m| m.into()),
+        }
+    }
+}
+
+/// Response body for a list of workers
+pub type ListWorkersResponse<'a> = PaginatedResponse<'a, WorkerInfo<'a>>;
+
+#[cfg(test)]
+mod tests {
+    use minicbor::Decoder;
+
+    use super::*;
+
+    #[test]
+    fn list_workers_response() {
+        let workers = vec![
+            WorkerInfo::new("app", false, Some("application worker")),
+            WorkerInfo::new("echo", true, None::<&str>),
+            WorkerInfo::new("_internal.nodemanager", false, Some("node manager")),
+        ];
+        let res = ListWorkersResponse::complete(workers.clone());
+        let bytes = minicbor::to_vec(&res).unwrap();
+        let dec: ListWorkersResponse = minicbor::decode(&bytes).unwrap();
+        assert_eq!(workers, dec.items);
+        assert_eq!(dec.items[1].address, "echo");
+        assert!(dec.items[1].is_paused);
+        assert_eq!(dec.items[0].metadata.as_deref(), Some("application worker"));
+    }
+
+    #[test]
+    fn absent_metadata() {
+        let len = if cfg!(feature = "tag") { 3 } else { 2 };
+        let info = WorkerInfo::new("echo", false, None::<&str>);
+        let bytes = minicbor::to_vec(&info).unwrap();
+        assert_eq!(Some(len), Decoder::new(&bytes).map().unwrap());
+
+        let info = WorkerInfo::new("echo", false, Some("echoes messages"));
+        let bytes = minicbor::to_vec(&info).unwrap();
+        assert_eq!(Some(len + 1), Decoder::new(&bytes).map().unwrap());
+    }
+}
@@ -72,7 +72,22 @@ impl SecureChannelInfo {
 }
 
 #[derive(Default)]
-pub(crate) struct SecureChannelListenerInfo {}
+pub(crate) struct SecureChannelListenerInfo {
+    authorized_identifiers: Option<Vec<IdentityIdentifier>>,
+}
+
+impl SecureChannelListenerInfo {
+    pub(crate) fn new(authorized_identifiers: Option<Vec<IdentityIdentifier>>) -> Self {
+        Self {
+            authorized_identifiers,
+        }
+    }
+
+    /// The identifiers allowed to connect; if absent, any identity is accepted.
+    pub(crate) fn authorized_identifiers(&self) -> Option<&[IdentityIdentifier]> {
+        self.authorized_identifiers.as_deref()
+    }
+}
 
 #[derive(Default)]
 pub(crate) struct VaultServiceInfo {}
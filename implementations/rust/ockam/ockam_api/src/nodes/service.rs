@@ -3,6 +3,7 @@
 use std::collections::BTreeMap;
 use std::error::Error as _;
 use std::path::PathBuf;
+use std::time::Instant;
 
 use minicbor::Decoder;
 
@@ -23,6 +24,7 @@ use ockam_node::tokio::task::JoinHandle;
 use ockam_vault::storage::FileStorage;
 use ockam_vault::Vault;
 
+use super::idempotency::IdempotencyCache;
 use super::registry::Registry;
 use crate::config::lookup::ProjectLookup;
 use crate::config::{cli::AuthoritiesConfig, Config};
@@ -38,12 +40,14 @@ pub mod message;
 
 mod credentials;
 mod forwarder;
+mod health;
 mod identity;
 mod portals;
 mod secure_channel;
 mod services;
 mod transport;
 mod vault;
+mod workers;
 
 const TARGET: &str = "ockam_api::nodemanager::service";
 
@@ -110,8 +114,10 @@ pub struct NodeManager {
     authorities: Option<Authorities>,
     pub(crate) authenticated_storage: LmdbStorage,
     pub(crate) registry: Registry,
+    idempotency_cache: IdempotencyCache,
     sessions: Arc<Mutex<Sessions>>,
     medic: JoinHandle<Result<(), ockam_core::Error>>,
+    started_at: Instant,
 }
 
 pub struct NodeManagerWorker {
@@ -329,11 +335,13 @@ impl NodeManager {
             authorities: None,
             authenticated_storage,
             registry: Default::default(),
+            idempotency_cache: Default::default(),
             medic: {
                 let ctx = ctx.async_try_clone().await?;
                 tokio::spawn(medic.start(ctx))
             },
             sessions,
+            started_at: Instant::now(),
         };
 
         if !general_options.skip_defaults {
@@ -430,6 +438,18 @@ impl NodeManagerWorker {
             None => todo!(),
         };
 
+        // A retried request gets the response to its first transmission:
+        let idempotency_key = req.idempotency_key().filter(|_| !method.is_idempotent());
+        if let Some(key) = idempotency_key {
+            let node_manager = self.node_manager.read().await;
+            if let Some(r) = node_manager
+                .idempotency_cache
+                .get(req.id(), key, method, path)?
+            {
+                return Ok(r);
+            }
+        }
+
         let r = match (method, path_segments.as_slice()) {
             // ==*== Basic node information ==*==
             // TODO: create, delete, destroy remote nodes
@@ -446,6 +466,8 @@ impl NodeManagerWorker {
                     .to_vec()?
             }
 
+            (Get, ["node", "health"]) => self.health_check(req).await?,
+
             // ==*== Tcp Connection ==*==
             // TODO: Get all tcp connections
             (Get, ["node", "tcp", "connection"]) => {
@@ -453,12 +475,8 @@ impl NodeManagerWorker {
                 self.get_tcp_con_or_list(req, &node_manager.transports, TransportMode::Connect)
                     .to_vec()?
             }
-            (Post, ["node", "tcp", "connection"]) => {
-                self.add_transport(req, dec).await?.to_vec()?
-            }
-            (Delete, ["node", "tcp", "connection"]) => {
-                self.delete_transport(req, dec).await?.to_vec()?
-            }
+            (Post, ["node", "tcp", "connection"]) => self.create_tcp_connection(req, dec).await?,
+            (Delete, ["node", "tcp", "connection"]) => self.delete_tcp_connection(req, dec).await?,
 
             // ==*== Tcp Listeners ==*==
             (Get, ["node", "tcp", "listener"]) => {
@@ -480,6 +498,7 @@ impl NodeManagerWorker {
 
             // ==*== Identity ==*==
             (Post, ["node", "identity"]) => self.create_identity(ctx, req).await?.to_vec()?,
+            (Get, ["node", "identity"]) => self.get_identity(req).await?.to_vec()?,
             (Post, ["node", "identity", "actions", "show", "short"]) => {
                 self.short_identity(req).await?.to_vec()?
             }
@@ -520,6 +539,10 @@ impl NodeManagerWorker {
                 .create_secure_channel_listener(req, dec)
                 .await?
                 .to_vec()?,
+            (Delete, ["node", "secure_channel_listener"]) => self
+                .delete_secure_channel_listener(ctx, req, dec)
+                .await?
+                .to_vec()?,
 
             // ==*== Services ==*==
             (Post, ["node", "services", "vault"]) => {
@@ -555,6 +578,9 @@ impl NodeManagerWorker {
                 self.list_services(req, &node_manager.registry).to_vec()?
             }
 
+            // ==*== Workers ==*==
+            (Get, ["node", "workers"]) => self.list_workers(ctx, req).await?,
+
             // ==*== Forwarder commands ==*==
             (Post, ["node", "forwarder"]) => self.create_forwarder(ctx, req.id(), dec).await?,
 
@@ -577,6 +603,12 @@ impl NodeManagerWorker {
             (Get, ["v0", "spaces", id]) => self.get_space(ctx, dec, id).await?,
             (Delete, ["v0", "spaces", id]) => self.delete_space(ctx, dec, id).await?,
 
+            // ==*== Invitations ==*==
+            (Post, ["v0", "invitations"]) => self.create_invitation(ctx, dec).await?,
+            (Get, ["v0", "invitations"]) => self.list_invitations(ctx, dec).await?,
+            (Put, ["v0", "invitations", id]) => self.accept_invitation(ctx, dec, id).await?,
+            (Delete, ["v0", "invitations", id]) => self.reject_invitation(ctx, dec, id).await?,
+
             // ==*== Project' enrollers ==*==
             (Post, ["v0", "project-enrollers", project_id]) => {
                 self.add_project_enroller(ctx, dec, project_id).await?
@@ -618,6 +650,7 @@ impl NodeManagerWorker {
 
             // ==*== Messages ==*==
             (Post, ["v0", "message"]) => self.send_message(ctx, req, dec).await?,
+            (Post, ["node", "message"]) => self.send_node_message(ctx, req, dec).await?,
 
             // ==*== Catch-all for Unimplemented APIs ==*==
             _ => {
@@ -627,6 +660,12 @@ impl NodeManagerWorker {
                     .to_vec()?
             }
         };
+        if let Some(key) = idempotency_key {
+            let mut node_manager = self.node_manager.write().await;
+            node_manager
+                .idempotency_cache
+                .insert(key, method, path, &r)?;
+        }
         Ok(r)
     }
 }
@@ -736,4 +775,53 @@ pub(crate) mod tests {
             Ok(route![node_manager])
         }
     }
+
+    #[ockam_macros::test]
+    async fn idempotency_key(ctx: &mut Context) -> Result<()> {
+        use crate::nodes::models::services::{
+            ListServicesResponse, ServiceType, StartEchoerServiceRequest,
+        };
+        use ockam_core::api::parse_response;
+
+        let node_manager = NodeManager::test_create(ctx).await?;
+        let start_echoer = |key: &str| {
+            Request::post("/node/services/echo")
+                .idempotency_key(key.to_string())
+                .body(StartEchoerServiceRequest::new("echoer"))
+                .to_vec()
+        };
+
+        // Retransmissions get the response to the first request:
+        let first: Vec<u8> = ctx
+            .send_and_receive(node_manager.clone(), start_echoer("k1")?)
+            .await?;
+        let retry: Vec<u8> = ctx
+            .send_and_receive(node_manager.clone(), start_echoer("k1")?)
+            .await?;
+        let (first, first_body) = parse_response(&first)?;
+        let (retry, retry_body) = parse_response(&retry)?;
+        assert_eq!(Some(Status::Ok), first.status());
+        assert_eq!(first.status(), retry.status());
+        assert_eq!(first_body, retry_body);
+
+        // ... and create no new echoer, which a different key would try:
+        let other: Vec<u8> = ctx
+            .send_and_receive(node_manager.clone(), start_echoer("k2")?)
+            .await?;
+        let (other, _) = parse_response(&other)?;
+        assert_eq!(Some(Status::InternalServerError), other.status());
+
+        let list: Vec<u8> = ctx
+            .send_and_receive(node_manager, Request::get("/node/services").to_vec()?)
+            .await?;
+        let (res, body) = parse_response(&list)?;
+        let list: ListServicesResponse = res.decode_body(body)?;
+        let echoers = list
+            .items
+            .iter()
+            .filter(|s| s.address == "echoer" && matches!(s.service_type, ServiceType::Echo));
+        assert_eq!(1, echoers.count());
+
+        ctx.stop().await
+    }
 }
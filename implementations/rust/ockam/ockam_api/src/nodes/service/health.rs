@@ -0,0 +1,30 @@
+use ockam::Result;
+use ockam_core::api::{Request, Response};
+
+use crate::nodes::models::node::HealthCheckResponse;
+
+use super::NodeManagerWorker;
+
+impl NodeManagerWorker {
+    /// Report the health of this node.
+    ///
+    /// A node whose session medic has stopped is considered degraded and
+    /// answers with "503 Service Unavailable".
+    pub(super) async fn health_check(&self, req: &Request<'_>) -> Result<Vec<u8>> {
+        let node_manager = self.node_manager.read().await;
+        let is_healthy = !node_manager.medic.is_finished();
+        let body = HealthCheckResponse::builder(
+            node_manager.node_name.as_str(),
+            env!("CARGO_PKG_VERSION"),
+        )
+        .uptime_secs(node_manager.started_at.elapsed().as_secs())
+        .is_healthy(is_healthy)
+        .build();
+        let res = if is_healthy {
+            Response::ok(req.id())
+        } else {
+            Response::service_unavailable(req.id())
+        };
+        Ok(res.body(body).to_vec()?)
+    }
+}
@@ -1,6 +1,7 @@
 use super::{map_anyhow_err, NodeManagerWorker};
 use crate::nodes::models::identity::{
-    CreateIdentityResponse, LongIdentityResponse, ShortIdentityResponse,
+    CreateIdentityResponse, GetIdentityResponse, IdentityInfo, LongIdentityResponse,
+    ShortIdentityResponse,
 };
 use crate::nodes::NodeManager;
 use ockam::identity::{Identity, IdentityIdentifier};
@@ -52,9 +53,25 @@ impl NodeManagerWorker {
     ) -> Result<ResponseBuilder<CreateIdentityResponse<'_>>> {
         let mut node_manager = self.node_manager.write().await;
         let identifier = node_manager.create_identity_impl(ctx, false).await?;
+        let change_history = node_manager.identity()?.export().await?;
 
-        let response =
-            Response::ok(req.id()).body(CreateIdentityResponse::new(identifier.to_string()));
+        let info = IdentityInfo::new(identifier.to_string(), change_history, true);
+        let response = Response::ok(req.id()).body(CreateIdentityResponse::new(info));
+        Ok(response)
+    }
+
+    /// Get the node's identity, which is always its default one.
+    pub(super) async fn get_identity(
+        &mut self,
+        req: &Request<'_>,
+    ) -> Result<ResponseBuilder<GetIdentityResponse<'_>>> {
+        let node_manager = self.node_manager.read().await;
+        let identity = node_manager.identity()?;
+        let identifier = identity.identifier().to_string();
+        let change_history = identity.export().await?;
+
+        let info = IdentityInfo::new(identifier, change_history, true);
+        let response = Response::ok(req.id()).body(GetIdentityResponse::new(info));
         Ok(response)
     }
 
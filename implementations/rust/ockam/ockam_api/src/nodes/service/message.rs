@@ -40,13 +40,17 @@ impl<'a> SendMessage<'a> {
 }
 
 mod node {
+    use std::time::{Duration, Instant};
+
     use minicbor::Decoder;
     use tracing::trace;
 
     use ockam_core::api::{Request, Response, Status};
-    use ockam_core::{self, Result};
+    use ockam_core::errcode::Kind;
+    use ockam_core::{self, Address, Result};
     use ockam_node::Context;
 
+    use crate::nodes::models::messages::{SendMessageRequest, SendMessageResponse};
     use crate::nodes::NodeManagerWorker;
 
     const TARGET: &str = "ockam_api::message";
@@ -58,6 +62,11 @@ mod node {
             req: &Request<'_>,
             dec: &mut Decoder<'_>,
         ) -> Result<Vec<u8>> {
+            if req.is_expired() {
+                trace!(target: TARGET, id = %req.id(), "request deadline expired");
+                return Ok(Response::gateway_timeout(req.id()).to_vec()?);
+            }
+
             let req_body: super::SendMessage = dec.decode()?;
             let route = req_body.route()?;
             let msg = req_body.message.to_vec();
@@ -76,5 +85,37 @@ mod node {
                 }
             }
         }
+
+        /// Send a message and wait up to the request's timeout for a reply.
+        pub(crate) async fn send_node_message(
+            &mut self,
+            ctx: &Context,
+            req: &Request<'_>,
+            dec: &mut Decoder<'_>,
+        ) -> Result<Vec<u8>> {
+            let req_body: SendMessageRequest = dec.decode()?;
+            let route = req_body.route()?;
+            let timeout = Duration::from_millis(req_body.timeout_ms.into());
+
+            trace!(target: TARGET, %route, msg_l = %req_body.payload.len(), "sending message");
+
+            let start = Instant::now();
+            let mut child_ctx = ctx.new_detached(Address::random_local()).await?;
+            child_ctx.send(route, req_body.payload.to_vec()).await?;
+            let reply = match child_ctx.receive_duration_timeout::<Vec<u8>>(timeout).await {
+                Ok(m) => Some(m.take().body()),
+                Err(err) if err.code().kind == Kind::Timeout => None,
+                Err(err) => {
+                    error!(target: TARGET, ?err, "Failed to receive reply");
+                    return Ok(Response::internal_error(req.id())
+                        .body(err.to_string())
+                        .to_vec()?);
+                }
+            };
+            let elapsed_ms = u32::try_from(start.elapsed().as_millis()).unwrap_or(u32::MAX);
+            Ok(Response::ok(req.id())
+                .body(SendMessageResponse::new(reply, elapsed_ms))
+                .to_vec()?)
+        }
     }
 }
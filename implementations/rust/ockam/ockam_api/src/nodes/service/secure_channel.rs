@@ -3,22 +3,39 @@ use std::time::Duration;
 use super::{map_multiaddr_err, NodeManagerWorker};
 use crate::error::ApiError;
 use crate::nodes::models::secure_channel::{
-    CreateSecureChannelListenerRequest, CreateSecureChannelRequest, CreateSecureChannelResponse,
-    CredentialExchangeMode, DeleteSecureChannelRequest, DeleteSecureChannelResponse,
-    ShowSecureChannelRequest, ShowSecureChannelResponse,
+    CreateSecureChannelRequest, CreateSecureChannelResponse, CredentialExchangeMode,
+    DeleteSecureChannelRequest, DeleteSecureChannelResponse, ShowSecureChannelRequest,
+    ShowSecureChannelResponse,
 };
-use crate::nodes::registry::Registry;
+use crate::nodes::models::secure_channel_listener::{
+    CreateSecureChannelListenerRequest, DeleteSecureChannelListenerRequest,
+    ListSecureChannelListenersResponse, SecureChannelListenerInfo,
+};
+use crate::nodes::registry::{self, Registry};
 use crate::nodes::NodeManager;
 use crate::DefaultAddress;
 use minicbor::Decoder;
 use ockam::identity::TrustEveryonePolicy;
-use ockam::{Address, Result, Route};
+use ockam::{Address, Context, Result, Route};
 use ockam_core::api::{Request, Response, ResponseBuilder};
 use ockam_core::{route, AsyncTryClone};
-use ockam_identity::{Identity, IdentityIdentifier, TrustMultiIdentifiersPolicy};
+use ockam_identity::{Identity, IdentityIdentifier, TrustMultiIdentifiersPolicy, TrustPolicy};
 use ockam_multiaddr::MultiAddr;
 use ockam_vault::Vault;
 
+/// The trust policy of a secure channel listener.
+///
+/// Without a list of identifiers any identity is trusted, otherwise only
+/// the listed ones are (i.e. none if the list is empty).
+fn listener_trust_policy(
+    authorized_identifiers: Option<Vec<IdentityIdentifier>>,
+) -> Box<dyn TrustPolicy> {
+    match authorized_identifiers {
+        Some(ids) => Box::new(TrustMultiIdentifiersPolicy::new(ids)),
+        None => Box::new(TrustEveryonePolicy),
+    }
+}
+
 impl NodeManager {
     async fn get_credential_if_needed(&mut self) -> Result<()> {
         let identity = self.identity()?;
@@ -146,30 +163,18 @@ impl NodeManager {
 
         let identity = self.identity()?;
 
-        match authorized_identifiers {
-            Some(ids) => {
-                identity
-                    .create_secure_channel_listener(
-                        addr.clone(),
-                        TrustMultiIdentifiersPolicy::new(ids),
-                        &self.authenticated_storage,
-                    )
-                    .await
-            }
-            None => {
-                identity
-                    .create_secure_channel_listener(
-                        addr.clone(),
-                        TrustEveryonePolicy,
-                        &self.authenticated_storage,
-                    )
-                    .await
-            }
-        }?;
+        identity
+            .create_secure_channel_listener(
+                addr.clone(),
+                listener_trust_policy(authorized_identifiers.clone()),
+                &self.authenticated_storage,
+            )
+            .await?;
 
-        self.registry
-            .secure_channel_listeners
-            .insert(addr, Default::default());
+        self.registry.secure_channel_listeners.insert(
+            addr,
+            registry::SecureChannelListenerInfo::new(authorized_identifiers),
+        );
 
         Ok(())
     }
@@ -195,14 +200,16 @@ impl NodeManagerWorker {
         &self,
         req: &Request<'_>,
         registry: &Registry,
-    ) -> ResponseBuilder<Vec<String>> {
-        Response::ok(req.id()).body(
+    ) -> ResponseBuilder<ListSecureChannelListenersResponse> {
+        Response::ok(req.id()).body(ListSecureChannelListenersResponse::complete(
             registry
                 .secure_channel_listeners
                 .iter()
-                .map(|(addr, _)| addr.to_string())
+                .map(|(addr, info)| {
+                    SecureChannelListenerInfo::new(addr, info.authorized_identifiers(), true)
+                })
                 .collect(),
-        )
+        ))
     }
 
     pub(super) async fn create_secure_channel<'a>(
@@ -314,24 +321,21 @@ impl NodeManagerWorker {
     ) -> Result<ResponseBuilder<()>> {
         let mut node_manager = self.node_manager.write().await;
         let CreateSecureChannelListenerRequest {
-            addr,
+            address,
             authorized_identifiers,
             ..
         } = dec.decode()?;
 
         let authorized_identifiers = match authorized_identifiers {
-            Some(ids) => {
-                let ids = ids
-                    .into_iter()
-                    .map(|x| IdentityIdentifier::try_from(x.0.as_ref()))
-                    .collect::<Result<Vec<IdentityIdentifier>>>()?;
-
-                Some(ids)
-            }
+            Some(ids) => ids
+                .into_iter()
+                .map(|x| IdentityIdentifier::try_from(x.0.as_ref()))
+                .collect::<Result<Vec<IdentityIdentifier>>>()
+                .map(Some)?,
             None => None,
         };
 
-        let addr = Address::from(addr.as_ref());
+        let addr = Address::from(address.as_ref());
         if !addr.is_local() {
             return Ok(Response::bad_request(req.id()));
         }
@@ -344,4 +348,62 @@ impl NodeManagerWorker {
 
         Ok(response)
     }
+
+    pub(super) async fn delete_secure_channel_listener(
+        &mut self,
+        ctx: &Context,
+        req: &Request<'_>,
+        dec: &mut Decoder<'_>,
+    ) -> Result<ResponseBuilder<()>> {
+        let body: DeleteSecureChannelListenerRequest = dec.decode()?;
+        info!(
+            "Handling request to delete secure channel listener: {}",
+            body.address
+        );
+
+        let mut node_manager = self.node_manager.write().await;
+        let addr = Address::from(body.address.as_ref());
+        if !node_manager
+            .registry
+            .secure_channel_listeners
+            .contains_key(&addr)
+        {
+            return Ok(Response::not_found(req.id()));
+        }
+        ctx.stop_worker(addr.clone()).await?;
+        node_manager.registry.secure_channel_listeners.remove(&addr);
+
+        Ok(Response::ok(req.id()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ockam_identity::SecureChannelTrustInfo;
+
+    #[ockam_macros::test]
+    async fn listener_trust(ctx: &mut Context) -> Result<()> {
+        let alice = IdentityIdentifier::from_key_id("6474cfdbf547240b6d716bff89c97681");
+        let bob = IdentityIdentifier::from_key_id("4c5f11a0c4b00ef1a8ee1a4d5e0bfac7");
+        let alice_info = SecureChannelTrustInfo::new(alice.clone());
+        let bob_info = SecureChannelTrustInfo::new(bob);
+
+        // No list of identifiers trusts everyone:
+        let policy = listener_trust_policy(None);
+        assert!(policy.check(&alice_info).await?);
+        assert!(policy.check(&bob_info).await?);
+
+        // An empty list trusts nobody:
+        let policy = listener_trust_policy(Some(vec![]));
+        assert!(!policy.check(&alice_info).await?);
+        assert!(!policy.check(&bob_info).await?);
+
+        // Otherwise only the listed identifiers are trusted:
+        let policy = listener_trust_policy(Some(vec![alice]));
+        assert!(policy.check(&alice_info).await?);
+        assert!(!policy.check(&bob_info).await?);
+
+        ctx.stop().await
+    }
 }
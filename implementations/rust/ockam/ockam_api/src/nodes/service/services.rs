@@ -3,9 +3,10 @@ use crate::echoer::Echoer;
 use crate::error::ApiError;
 use crate::identity::IdentityService;
 use crate::nodes::models::services::{
-    ServiceList, ServiceStatus, StartAuthenticatedServiceRequest, StartAuthenticatorRequest,
-    StartCredentialsService, StartEchoerServiceRequest, StartIdentityServiceRequest,
-    StartUppercaseServiceRequest, StartVaultServiceRequest, StartVerifierService,
+    ListServicesResponse, ServiceInfo, ServiceType, StartAuthenticatedServiceRequest,
+    StartAuthenticatorRequest, StartCredentialsService, StartEchoerServiceRequest,
+    StartIdentityServiceRequest, StartUppercaseServiceRequest, StartVaultServiceRequest,
+    StartVerifierService,
 };
 use crate::nodes::registry::{CredentialsServiceInfo, Registry, VerifierServiceInfo};
 use crate::nodes::NodeManager;
@@ -316,43 +317,46 @@ impl NodeManagerWorker {
         &self,
         req: &Request<'a>,
         registry: &'a Registry,
-    ) -> ResponseBuilder<ServiceList<'a>> {
+    ) -> ResponseBuilder<ListServicesResponse<'a>> {
         let mut list = Vec::new();
+        let mut add = |addr: &'a Address, service_type| {
+            list.push(ServiceInfo::new(addr.address(), service_type, true))
+        };
         registry
             .vault_services
             .keys()
-            .for_each(|addr| list.push(ServiceStatus::new(addr.address(), "vault")));
+            .for_each(|addr| add(addr, ServiceType::Vault));
         registry
             .identity_services
             .keys()
-            .for_each(|addr| list.push(ServiceStatus::new(addr.address(), "identity")));
+            .for_each(|addr| add(addr, ServiceType::Identity));
         registry
             .authenticated_services
             .keys()
-            .for_each(|addr| list.push(ServiceStatus::new(addr.address(), "authenticated")));
+            .for_each(|addr| add(addr, ServiceType::Other));
         registry
             .uppercase_services
             .keys()
-            .for_each(|addr| list.push(ServiceStatus::new(addr.address(), "uppercase")));
+            .for_each(|addr| add(addr, ServiceType::Uppercase));
         registry
             .echoer_services
             .keys()
-            .for_each(|addr| list.push(ServiceStatus::new(addr.address(), "echoer")));
+            .for_each(|addr| add(addr, ServiceType::Echo));
         registry
             .verifier_services
             .keys()
-            .for_each(|addr| list.push(ServiceStatus::new(addr.address(), "verifier")));
+            .for_each(|addr| add(addr, ServiceType::Verifier));
         registry
             .credentials_services
             .keys()
-            .for_each(|addr| list.push(ServiceStatus::new(addr.address(), "credentials")));
+            .for_each(|addr| add(addr, ServiceType::Credentials));
 
         #[cfg(feature = "direct-authenticator")]
         registry
             .authenticator_service
             .keys()
-            .for_each(|addr| list.push(ServiceStatus::new(addr.address(), "authenticator")));
+            .for_each(|addr| add(addr, ServiceType::Authenticator));
 
-        Response::ok(req.id()).body(ServiceList::new(list))
+        Response::ok(req.id()).body(ListServicesResponse::complete(list))
     }
 }
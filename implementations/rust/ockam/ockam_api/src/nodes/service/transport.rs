@@ -1,12 +1,14 @@
 use std::collections::BTreeMap;
 
 use crate::nodes::models::transport::{
-    CreateTransport, DeleteTransport, TransportList, TransportMode, TransportStatus, TransportType,
+    ConnectionDirection, ConnectionStatus, CreateTcpConnectionRequest, CreateTransport,
+    DeleteTransport, TcpConnectionInfo, TransportList, TransportMode, TransportStatus,
+    TransportType,
 };
 use crate::nodes::service::{random_alias, Alias};
 use minicbor::Decoder;
 use ockam::Result;
-use ockam_core::api::{Request, Response, ResponseBuilder};
+use ockam_core::api::{Error, Request, Response, ResponseBuilder};
 
 use super::NodeManagerWorker;
 
@@ -104,4 +106,75 @@ impl NodeManagerWorker {
             None => Ok(Response::bad_request(req.id())),
         }
     }
+
+    pub(super) async fn create_tcp_connection(
+        &self,
+        req: &Request<'_>,
+        dec: &mut Decoder<'_>,
+    ) -> Result<Vec<u8>> {
+        let mut node_manager = self.node_manager.write().await;
+        // The TCP transport does not encrypt, so `allow_insecure` has no
+        // effect on how the connection is established.
+        let CreateTcpConnectionRequest { peer, alias, .. } = dec.decode()?;
+        info!("Handling request to create a new tcp connection: {}", peer);
+
+        let tid: Alias = match alias {
+            Some(a) => a.to_string(),
+            None => random_alias(),
+        };
+        if node_manager.transports.contains_key(&tid) {
+            let err = Error::new(req.path()).with_message(format!("alias {tid} already exists"));
+            return Ok(Response::bad_request(req.id()).body(err).to_vec()?);
+        }
+
+        let peer = peer.to_string();
+        if let Err(e) = node_manager.tcp_transport.connect(&peer).await {
+            let err = Error::new(req.path()).with_message(e.to_string());
+            return Ok(Response::bad_request(req.id()).body(err).to_vec()?);
+        }
+        node_manager.transports.insert(
+            tid.clone(),
+            (TransportType::Tcp, TransportMode::Connect, peer.clone()),
+        );
+
+        let info = TcpConnectionInfo::new(
+            tid,
+            peer,
+            ConnectionDirection::Outgoing,
+            ConnectionStatus::Connected,
+        );
+        Ok(Response::ok(req.id()).body(info).to_vec()?)
+    }
+
+    pub(super) async fn delete_tcp_connection(
+        &self,
+        req: &Request<'_>,
+        dec: &mut Decoder<'_>,
+    ) -> Result<Vec<u8>> {
+        let mut node_manager = self.node_manager.write().await;
+        let body: DeleteTransport = dec.decode()?;
+        info!("Handling request to delete tcp connection: {}", body.tid);
+
+        let tid: Alias = body.tid.into();
+
+        if node_manager.api_transport_id == tid && !body.force {
+            warn!("User requested to delete the API transport without providing force OP flag...");
+            return Ok(Response::bad_request(req.id()).to_vec()?);
+        }
+
+        let peer = match node_manager.transports.get(&tid) {
+            Some((_, TransportMode::Connect, peer)) => peer.clone(),
+            _ => return Ok(Response::not_found(req.id()).to_vec()?),
+        };
+        node_manager.tcp_transport.disconnect(&peer).await?;
+        node_manager.transports.remove(&tid);
+
+        let info = TcpConnectionInfo::new(
+            tid,
+            peer,
+            ConnectionDirection::Outgoing,
+            ConnectionStatus::Disconnected,
+        );
+        Ok(Response::ok(req.id()).body(info).to_vec()?)
+    }
 }
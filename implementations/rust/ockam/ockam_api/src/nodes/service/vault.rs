@@ -1,5 +1,5 @@
 use super::{map_anyhow_err, NodeManagerWorker};
-use crate::nodes::models::vault::CreateVaultRequest;
+use crate::nodes::models::vault::{CreateVaultRequest, VaultInfo, VaultType};
 use crate::nodes::NodeManager;
 use minicbor::Decoder;
 use ockam::vault::storage::FileStorage;
@@ -50,19 +50,31 @@ impl NodeManager {
 }
 
 impl NodeManagerWorker {
-    pub(super) async fn create_vault(
+    pub(super) async fn create_vault<'a>(
         &mut self,
         req: &Request<'_>,
         dec: &mut Decoder<'_>,
-    ) -> Result<ResponseBuilder> {
+    ) -> Result<ResponseBuilder<VaultInfo<'a>>> {
         let mut node_manager = self.node_manager.write().await;
         let req_body: CreateVaultRequest = dec.decode()?;
 
+        if req_body.vault_type != VaultType::Software {
+            return Err(ockam_core::Error::new(
+                Origin::Application,
+                Kind::Unsupported,
+                "Only software vaults are supported",
+            ));
+        }
+
         let path = req_body.path.map(|p| PathBuf::from(p.0.as_ref()));
 
         node_manager.create_vault_impl(path, false).await?;
 
-        let response = Response::ok(req.id());
+        let response = Response::ok(req.id()).body(VaultInfo::new(
+            req_body.name.into_owned(),
+            true,
+            req_body.vault_type,
+        ));
 
         Ok(response)
     }
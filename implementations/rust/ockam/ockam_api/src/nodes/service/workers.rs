@@ -0,0 +1,42 @@
+use ockam::{Context, Result};
+use ockam_core::api::{bad_request, Request, Response};
+
+use crate::nodes::models::workers::{ListWorkersResponse, WorkerInfo};
+use crate::nodes::NODEMANAGER_ADDR;
+
+use super::NodeManagerWorker;
+
+impl NodeManagerWorker {
+    /// List the workers of this node.
+    ///
+    /// Internal workers are only included with `?include_internal=true`.
+    pub(super) async fn list_workers(&self, ctx: &Context, req: &Request<'_>) -> Result<Vec<u8>> {
+        let mut include_internal = false;
+        for (k, v) in req.query_params() {
+            if k == "include_internal" {
+                match v.parse() {
+                    Ok(b) => include_internal = b,
+                    Err(_) => return Ok(bad_request(req, "invalid include_internal").to_vec()?),
+                }
+            }
+        }
+        let workers = ctx
+            .list_workers()
+            .await?
+            .iter()
+            .map(|a| a.address())
+            .filter(|a| include_internal || !a.starts_with("_internal."))
+            .map(|a| {
+                let metadata = if a == NODEMANAGER_ADDR {
+                    Some("node manager")
+                } else {
+                    None
+                };
+                WorkerInfo::new(a.to_string(), false, metadata)
+            })
+            .collect();
+        Ok(Response::ok(req.id())
+            .body(ListWorkersResponse::complete(workers))
+            .to_vec()?)
+    }
+}
@@ -0,0 +1,114 @@
+//! Cursor based pagination of list endpoints
+
+use minicbor::{Decode, Encode};
+
+use ockam_core::CowStr;
+#[cfg(feature = "tag")]
+use ockam_core::TypeTag;
+
+/// Which page of a collection to return
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct Pagination<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] tag: TypeTag<6905148>,
+    /// The `next_cursor` of the previous page, or `None` for the first page
+    #[b(1)] pub cursor: Option<CowStr<'a>>,
+    /// The maximum number of items to return
+    #[n(2)] pub limit: u32,
+}
+
+impl<'a> Pagination<'a> {
+    pub fn new(cursor: Option<impl Into<CowStr<'a>>>, limit: u32) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            cursor: cursor.map(|c| c.into()),
+            limit,
+        }
+    }
+}
+
+/// Request body of a list endpoint
+#[derive(Debug, Clone, Default, PartialEq, Eq, Decode, Encode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct ListRequest<'a> {
+    #[cfg(feature = "tag")]
+    #[n(0)] tag: TypeTag<2317594>,
+    /// Return the whole collection if `None`
+    #[b(1)] pub pagination: Option<Pagination<'a>>,
+}
+
+impl<'a> ListRequest<'a> {
+    pub fn new(pagination: Option<Pagination<'a>>) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            pagination,
+        }
+    }
+}
+
+/// Response body of a list endpoint
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+#[rustfmt::skip]
+#[cbor(map)]
+pub struct PaginatedResponse<'a, T> {
+    #[cfg(feature = "tag")]
+    #[n(0)] tag: TypeTag<8150373>,
+    #[n(1)] pub items: Vec<T>,
+    /// The cursor to request the next page with, or `None` on the last page
+    #[b(2)] pub next_cursor: Option<CowStr<'a>>,
+}
+
+impl<'a, T> PaginatedResponse<'a, T> {
+    pub fn new(items: Vec<T>, next_cursor: Option<impl Into<CowStr<'a>>>) -> Self {
+        Self {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            items,
+            next_cursor: next_cursor.map(|c| c.into()),
+        }
+    }
+
+    /// A single page holding the whole collection.
+    pub fn complete(items: Vec<T>) -> Self {
+        Self::new(items, None::<CowStr>)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use minicbor::Decoder;
+
+    use super::*;
+
+    #[test]
+    fn absent_next_cursor() {
+        let res = PaginatedResponse::complete(vec![1u32, 2, 3]);
+        let bytes = minicbor::to_vec(&res).unwrap();
+        let mut d = Decoder::new(&bytes);
+        let len = if cfg!(feature = "tag") { 2 } else { 1 };
+        assert_eq!(Some(len), d.map().unwrap());
+        assert_eq!(res, minicbor::decode(&bytes).unwrap());
+
+        let res = PaginatedResponse::new(vec![1u32, 2, 3], Some("c2"));
+        let bytes = minicbor::to_vec(&res).unwrap();
+        let mut d = Decoder::new(&bytes);
+        assert_eq!(Some(len + 1), d.map().unwrap());
+        assert_eq!(res, minicbor::decode(&bytes).unwrap());
+    }
+
+    #[test]
+    fn list_request() {
+        let req = ListRequest::new(Some(Pagination::new(Some("c2"), 50)));
+        let bytes = minicbor::to_vec(&req).unwrap();
+        assert_eq!(req, minicbor::decode(&bytes).unwrap());
+
+        let req = ListRequest::default();
+        let bytes = minicbor::to_vec(&req).unwrap();
+        assert_eq!(req, minicbor::decode(&bytes).unwrap());
+    }
+}
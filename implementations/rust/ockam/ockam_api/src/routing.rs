@@ -0,0 +1,140 @@
+//! Dispatch of API requests to handlers by method and path.
+
+pub use ockam_core::api::{InvalidRoutePattern, PathParams, RoutePattern, MAX_PARAMS};
+
+use minicbor::Decoder;
+use ockam_core::api::{invalid_method, Error, Method, Request, Response};
+use ockam_core::Result;
+
+/// A table of routes, each consisting of a request method, a path pattern
+/// and a handler.
+///
+/// Routes are tried in the order in which they were added and the first
+/// route matching both method and path handles the request.
+pub struct Router<H> {
+    routes: Vec<(Method, RoutePattern, H)>,
+}
+
+impl<H> Default for Router<H> {
+    fn default() -> Self {
+        Router { routes: Vec::new() }
+    }
+}
+
+impl<H> Router<H>
+where
+    H: Fn(&Request, PathParams, Option<&[u8]>) -> Result<Vec<u8>>,
+{
+    pub fn new() -> Self {
+        Router::default()
+    }
+
+    /// Register a handler for the given method and path template.
+    ///
+    /// Fails if the template is not a valid [`RoutePattern`].
+    pub fn add(
+        &mut self,
+        method: Method,
+        pattern: &'static str,
+        handler: H,
+    ) -> Result<&mut Self, InvalidRoutePattern> {
+        self.routes
+            .push((method, RoutePattern::new(pattern)?, handler));
+        Ok(self)
+    }
+
+    /// Decode the request header, find the first matching route and invoke
+    /// its handler with the captured path parameters and the request body.
+    ///
+    /// If no route matches the path, a "not found" response is returned.
+    /// If the path matches but none of the methods, the response indicates
+    /// that the method is not allowed.
+    pub fn dispatch(&self, bytes: &[u8]) -> Result<Vec<u8>> {
+        let mut dec = Decoder::new(bytes);
+        let req: Request = dec.decode()?;
+        let body = req.has_body().then(|| &bytes[dec.position()..]);
+
+        let method = match req.method() {
+            Some(m) => m,
+            None => return Ok(invalid_method(&req).to_vec()?),
+        };
+
+        let mut known_path = false;
+        for (m, pattern, handler) in &self.routes {
+            if let Some(params) = pattern.extract(req.path()) {
+                if *m == method {
+                    return handler(&req, params, body);
+                }
+                known_path = true
+            }
+        }
+
+        if known_path {
+            Ok(invalid_method(&req).to_vec()?)
+        } else {
+            let e = Error::new(req.path()).with_method(method);
+            Ok(Response::not_found(req.id()).body(e).to_vec()?)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ockam_core::api::Status;
+
+    type Handler = fn(&Request, PathParams, Option<&[u8]>) -> Result<Vec<u8>>;
+
+    fn first(r: &Request, p: PathParams, _: Option<&[u8]>) -> Result<Vec<u8>> {
+        assert_eq!(Some("n1"), p.get("id"));
+        Ok(Response::ok(r.id()).body("first").to_vec()?)
+    }
+
+    fn second(r: &Request, _: PathParams, _: Option<&[u8]>) -> Result<Vec<u8>> {
+        Ok(Response::ok(r.id()).body("second").to_vec()?)
+    }
+
+    fn router() -> Router<Handler> {
+        let mut r = Router::<Handler>::new();
+        r.add(Method::Get, "/nodes/:id/services", first)
+            .and_then(|r| r.add(Method::Get, "/nodes/n1/services", second))
+            .unwrap();
+        r
+    }
+
+    fn decode(bytes: &[u8]) -> (Response, Option<String>) {
+        let mut dec = Decoder::new(bytes);
+        let res: Response = dec.decode().unwrap();
+        let body = res.has_body().then(|| dec.str().unwrap_or("").to_string());
+        (res, body)
+    }
+
+    #[test]
+    fn first_matching_route_wins() {
+        let req = Request::get("/nodes/n1/services").to_vec().unwrap();
+        let (res, body) = decode(&router().dispatch(&req).unwrap());
+        assert_eq!(Some(Status::Ok), res.status());
+        assert_eq!(Some("first"), body.as_deref())
+    }
+
+    #[test]
+    fn unknown_path_is_not_found() {
+        let req = Request::get("/nodes").to_vec().unwrap();
+        let (res, _) = decode(&router().dispatch(&req).unwrap());
+        assert_eq!(Some(Status::NotFound), res.status())
+    }
+
+    #[test]
+    fn invalid_pattern() {
+        let mut r = Router::<Handler>::new();
+        assert!(r.add(Method::Get, "/static/*/x", first).is_err());
+        assert!(r.routes.is_empty())
+    }
+
+    #[test]
+    fn unknown_method_is_not_allowed() {
+        let req = Request::post("/nodes/n1/services").to_vec().unwrap();
+        let (res, _) = decode(&router().dispatch(&req).unwrap());
+        assert_eq!(Some(Status::MethodNotAllowed), res.status())
+    }
+}
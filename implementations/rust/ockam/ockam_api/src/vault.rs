@@ -305,7 +305,9 @@ impl VaultService {
                 }
                 _ => Self::response_for_bad_request(req, "unknown path", enc),
             },
-            Put | Patch => Self::response_for_bad_request(req, "unknown method", enc),
+            Put | Patch | Head | Options => {
+                Self::response_for_bad_request(req, "unknown method", enc)
+            }
         }
     }
 
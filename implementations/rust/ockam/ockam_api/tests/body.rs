@@ -0,0 +1,42 @@
+use ockam_api::nodes::models::base::NodeStatus;
+use ockam_api::nodes::models::vault::{CreateVaultRequest, VaultType};
+use ockam_core::api::{parse_request, parse_response, Id, Request, Response, Status};
+
+#[test]
+fn request_body_roundtrip() {
+    let bytes = Request::post("/node/vault")
+        .body(CreateVaultRequest::new(
+            "v1",
+            Some("/tmp/vault"),
+            VaultType::Software,
+        ))
+        .to_vec()
+        .unwrap();
+    let (req, tail) = parse_request(&bytes).unwrap();
+    let body: CreateVaultRequest = req.decode_body(tail).unwrap();
+    assert_eq!(Some("/tmp/vault"), body.path.as_deref());
+}
+
+#[test]
+fn response_body_roundtrip() {
+    let bytes = Response::ok(Id::fresh())
+        .body(NodeStatus::new("n1", "Running", 3, 42, 1))
+        .to_vec()
+        .unwrap();
+    let (res, tail) = parse_response(&bytes).unwrap();
+    assert_eq!(Some(Status::Ok), res.status());
+    let body: NodeStatus = res.decode_body(tail).unwrap();
+    assert_eq!("n1", body.node_name);
+    assert_eq!(42, body.pid);
+}
+
+#[test]
+fn missing_body() {
+    let bytes = Request::get("/node").to_vec().unwrap();
+    let (req, tail) = parse_request(&bytes).unwrap();
+    assert!(req.decode_body::<NodeStatus>(tail).is_err());
+
+    let bytes = Response::not_found(Id::fresh()).to_vec().unwrap();
+    let (res, tail) = parse_response(&bytes).unwrap();
+    assert!(res.decode_body::<NodeStatus>(tail).is_err());
+}
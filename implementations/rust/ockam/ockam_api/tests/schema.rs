@@ -9,15 +9,30 @@ const METHODS: &[Method] = &[
     Method::Put,
     Method::Delete,
     Method::Patch,
+    Method::Head,
+    Method::Options,
 ];
 
 const STATUS: &[Status] = &[
     Status::Ok,
+    Status::Created,
+    Status::Accepted,
+    Status::NoContent,
     Status::BadRequest,
+    Status::Unauthorized,
+    Status::Forbidden,
     Status::NotFound,
     Status::MethodNotAllowed,
+    Status::Conflict,
+    Status::Gone,
+    Status::PreconditionFailed,
+    Status::UnprocessableEntity,
+    Status::Locked,
+    Status::TooManyRequests,
     Status::InternalServerError,
     Status::NotImplemented,
+    Status::ServiceUnavailable,
+    Status::GatewayTimeout,
 ];
 
 #[derive(Debug, Clone)]
@@ -6,7 +6,8 @@ use colorful::Colorful;
 use minicbor::Decoder;
 use ockam_api::config::cli::NodeConfig;
 use ockam_api::nodes::models::portal::{InletList, OutletList};
-use ockam_api::nodes::models::services::ServiceList;
+use ockam_api::nodes::models::secure_channel_listener::ListSecureChannelListenersResponse;
+use ockam_api::nodes::models::services::ListServicesResponse;
 use ockam_api::nodes::models::transport::TransportList;
 use ockam_api::nodes::NODEMANAGER_ADDR;
 use ockam_api::{addr_to_multiaddr, route_to_multiaddr};
@@ -56,7 +57,7 @@ fn print_node_info(
     node_name: &str,
     status: &str,
     default_id: &str,
-    services: Option<&ServiceList>,
+    services: Option<&ListServicesResponse>,
     tcp_listeners: Option<&TransportList>,
     secure_channel_listeners: Option<&Vec<String>>,
     inlets_outlets: Option<(&InletList, &OutletList)>,
@@ -131,10 +132,10 @@ fn print_node_info(
 
     if let Some(list) = services {
         println!("  Services:");
-        for e in &list.list {
+        for e in &list.items {
             println!("    Service:");
             println!("      Type: {}", e.service_type);
-            if let Some(ma) = addr_to_multiaddr(e.addr.as_ref()) {
+            if let Some(ma) = addr_to_multiaddr(e.address.as_ref()) {
                 println!("      Address: {}", ma);
             }
         }
@@ -202,7 +203,12 @@ pub async fn print_query_status(
             .context("Failed to get list of secure channel listeners from node")?;
         let mut dec = Decoder::new(&resp);
         let _ = dec.decode::<Response>()?;
-        let secure_channel_listeners = dec.decode::<Vec<String>>()?;
+        let secure_channel_listeners = dec
+            .decode::<ListSecureChannelListenersResponse>()?
+            .items
+            .into_iter()
+            .map(|l| l.address.to_string())
+            .collect::<Vec<_>>();
 
         // Get list of inlets
         let resp: Vec<u8> = ctx
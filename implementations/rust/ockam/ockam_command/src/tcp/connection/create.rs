@@ -5,10 +5,7 @@ use crate::{
 use clap::Args;
 use colorful::Colorful;
 use ockam::{Context, Route, TCP};
-use ockam_api::{
-    nodes::{models::transport::TransportStatus, NODEMANAGER_ADDR},
-    route_to_multiaddr,
-};
+use ockam_api::{nodes::NODEMANAGER_ADDR, route_to_multiaddr};
 use ockam_core::api::Status;
 use serde_json::json;
 use std::net::SocketAddrV4;
@@ -65,14 +62,14 @@ pub async fn create_connection(
         }
     };
 
-    let (response, TransportStatus { payload, .. }) = api::parse_transport_status(&resp)?;
+    let (response, info) = api::parse_tcp_connection_info(&resp)?;
 
-    match response.status() {
-        Some(Status::Ok) => {
+    match (response.status(), info) {
+        (Some(Status::Ok), Ok(info)) => {
             let r: Route = base_route
                 .modify()
                 .pop_back()
-                .append_t(TCP, payload.to_string())
+                .append_t(TCP, info.peer.to_string())
                 .into();
             let multiaddr = match route_to_multiaddr(&r) {
                 Some(addr) => addr,
@@ -108,10 +105,10 @@ pub async fn create_connection(
                 }
             }
         }
-        _ => {
+        (_, info) => {
             eprintln!(
                 "An error occurred while creating the tcp connection: {}",
-                payload
+                info.err().unwrap_or_default()
             );
             std::process::exit(exitcode::CANTCREAT);
         }
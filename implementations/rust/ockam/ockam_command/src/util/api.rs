@@ -19,7 +19,7 @@ use ockam_api::cloud::{BareCloudRequestWrapper, CloudRequestWrapper};
 use ockam_api::nodes::models::secure_channel::CredentialExchangeMode;
 use ockam_api::nodes::*;
 use ockam_core::api::RequestBuilder;
-use ockam_core::api::{Request, Response};
+use ockam_core::api::{Request, Response, Status};
 use ockam_core::Address;
 use ockam_multiaddr::MultiAddr;
 
@@ -51,13 +51,11 @@ pub(crate) fn list_tcp_listeners() -> RequestBuilder<'static, ()> {
 pub(crate) fn create_tcp_connection(
     cmd: &crate::tcp::connection::CreateCommand,
 ) -> Result<Vec<u8>> {
-    let (tt, addr) = (
-        models::transport::TransportMode::Connect,
+    let payload = models::transport::CreateTcpConnectionRequest::new(
         cmd.address.clone(),
+        None::<String>,
+        false,
     );
-
-    let payload =
-        models::transport::CreateTransport::new(models::transport::TransportType::Tcp, tt, addr);
     let mut buf = vec![];
     Request::post("/node/tcp/connection")
         .body(payload)
@@ -158,7 +156,7 @@ pub(crate) fn create_secure_channel_listener(
     addr: &Address,
     authorized_identifiers: Option<Vec<IdentityIdentifier>>,
 ) -> Result<Vec<u8>> {
-    let payload = models::secure_channel::CreateSecureChannelListenerRequest::new(
+    let payload = models::secure_channel_listener::CreateSecureChannelListenerRequest::new(
         addr,
         authorized_identifiers,
     );
@@ -381,6 +379,28 @@ pub(crate) fn parse_tcp_list(resp: &[u8]) -> Result<models::transport::Transport
     Ok(dec.decode::<models::transport::TransportList>()?)
 }
 
+/// Parse the response to a tcp connection request
+///
+/// Returns the connection info if successful, or the error message otherwise.
+pub(crate) fn parse_tcp_connection_info(
+    resp: &[u8],
+) -> Result<(
+    Response,
+    std::result::Result<models::transport::TcpConnectionInfo<'_>, String>,
+)> {
+    let mut dec = Decoder::new(resp);
+    let response = dec.decode::<Response>()?;
+    let info = if response.status() == Some(Status::Ok) {
+        Ok(dec.decode::<models::transport::TcpConnectionInfo>()?)
+    } else {
+        let err = dec.decode::<ockam_core::api::Error>().ok();
+        Err(err
+            .and_then(|e| e.message().map(String::from))
+            .unwrap_or_default())
+    };
+    Ok((response, info))
+}
+
 /// Parse the returned status response
 pub(crate) fn parse_transport_status(
     resp: &[u8],
@@ -415,10 +435,12 @@ pub(crate) fn parse_short_identity_response(
     ))
 }
 
-pub(crate) fn parse_list_services_response(resp: &[u8]) -> Result<models::services::ServiceList> {
+pub(crate) fn parse_list_services_response(
+    resp: &[u8],
+) -> Result<models::services::ListServicesResponse> {
     let mut dec = Decoder::new(resp);
     let _ = dec.decode::<Response>()?;
-    Ok(dec.decode::<models::services::ServiceList>()?)
+    Ok(dec.decode::<models::services::ListServicesResponse>()?)
 }
 
 pub(crate) fn parse_list_inlets_response(resp: &[u8]) -> Result<models::portal::InletList> {
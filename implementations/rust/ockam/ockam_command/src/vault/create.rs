@@ -3,7 +3,7 @@ use crate::util::{node_rpc, Rpc};
 use crate::CommandGlobalOpts;
 use clap::Args;
 use ockam::Context;
-use ockam_api::nodes::models::vault::CreateVaultRequest;
+use ockam_api::nodes::models::vault::{CreateVaultRequest, VaultInfo, VaultType};
 use ockam_core::api::Request;
 
 /// Create vaults
@@ -12,6 +12,10 @@ pub struct CreateCommand {
     #[command(flatten)]
     node_opts: NodeOpts,
 
+    /// Name of the Vault
+    #[arg(long, default_value = "default")]
+    pub name: String,
+
     /// Path to the Vault storage file
     #[arg(short, long)]
     pub path: Option<String>,
@@ -28,11 +32,15 @@ async fn run_impl(
     (options, cmd): (CommandGlobalOpts, CreateCommand),
 ) -> crate::Result<()> {
     let mut rpc = Rpc::background(&ctx, &options, &cmd.node_opts.api_node)?;
-    let request = Request::post("/node/vault").body(CreateVaultRequest::new(cmd.path));
+    let request = Request::post("/node/vault").body(CreateVaultRequest::new(
+        cmd.name,
+        cmd.path,
+        VaultType::Software,
+    ));
 
     rpc.request(request).await?;
-    rpc.parse_response()?;
+    let info = rpc.parse_response::<VaultInfo>()?;
 
-    println!("Vault created!");
+    println!("Vault {} created!", info.name);
     Ok(())
 }
@@ -0,0 +1,33 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ockam_core::api::{Segments, SegmentsIter};
+
+const PATH: &str = "/nodes/create";
+
+fn first_segment(c: &mut Criterion) {
+    let mut g = c.benchmark_group("first segment");
+    g.bench_function("Segments::parse", |b| {
+        b.iter(|| Segments::<8>::parse(black_box(PATH)).head())
+    });
+    g.bench_function("SegmentsIter::new", |b| {
+        b.iter(|| SegmentsIter::new(black_box(PATH), 8).next())
+    });
+    g.finish()
+}
+
+fn all_segments(c: &mut Criterion) {
+    let mut g = c.benchmark_group("all segments");
+    g.bench_function("Segments::parse", |b| {
+        b.iter(|| Segments::<8>::parse(black_box(PATH)).into_iter().count())
+    });
+    g.bench_function("SegmentsIter::new", |b| {
+        b.iter(|| SegmentsIter::new(black_box(PATH), 8).count())
+    });
+    let s = Segments::<8>::parse(PATH);
+    g.bench_function("Segments::iter", |b| {
+        b.iter(|| black_box(&s).iter().count())
+    });
+    g.finish()
+}
+
+criterion_group!(benches, first_segment, all_segments);
+criterion_main!(benches);
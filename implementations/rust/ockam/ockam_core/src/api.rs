@@ -2,21 +2,46 @@
 
 use crate::compat::borrow::Cow;
 use crate::compat::rand;
+use crate::compat::string::String;
 use crate::compat::vec::Vec;
 use crate::errcode::{Kind, Origin};
-use crate::Result;
+use crate::{CowBytes, CowStr, Result};
 use core::fmt::{self, Display, Formatter};
+use core::marker::PhantomData;
+use core::str::FromStr;
+use minicbor::decode;
 use minicbor::encode::{self, Encoder, Write};
 use minicbor::{Decode, Decoder, Encode};
+#[cfg(feature = "api-serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use tinyvec::ArrayVec;
 
-#[cfg(feature = "tag")]
-use crate::TypeTag;
+use crate::{Tagged, TypeTag};
+
+mod codec;
+#[cfg(feature = "http")]
+mod http;
+mod pattern;
+mod router;
+mod snapshot;
+#[cfg(feature = "std")]
+pub mod test_support;
+#[cfg(feature = "validate")]
+mod validate;
+
+#[cfg(feature = "http")]
+pub use self::http::*;
+pub use codec::*;
+pub use pattern::*;
+pub use snapshot::*;
+#[cfg(feature = "validate")]
+pub use validate::*;
 
 pub const SCHEMA: &str = core::include_str!("schema.cddl");
 
 /// A request header.
 #[derive(Debug, Clone, Encode, Decode)]
+#[cfg_attr(feature = "api-serde", derive(Serialize, Deserialize))]
 #[rustfmt::skip]
 #[cbor(map)]
 pub struct Request<'a> {
@@ -27,7 +52,8 @@ pub struct Request<'a> {
     /// errors. Otherwise this tag will not be produced and is ignored during
     /// decoding if present.
     #[cfg(feature = "tag")]
-    #[n(0)] tag: TypeTag<7586022>,
+    #[cfg_attr(feature = "api-serde", serde(skip))]
+    #[n(0)] tag: RequestTag,
     /// The request identifier.
     #[n(1)] id: Id,
     /// The resource path.
@@ -39,11 +65,30 @@ pub struct Request<'a> {
     /// how to handle unknown methods.
     #[n(3)] method: Option<Method>,
     /// Indicator if a request body is expected after this header.
-    #[n(4)] has_body: bool
+    #[n(4)] has_body: bool,
+    /// The raw query string, e.g. "verbose=true&limit=50".
+    #[cfg_attr(feature = "api-serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    #[b(5)] query: Option<Cow<'a, str>>,
+    /// A bearer token authorizing the request.
+    #[cfg_attr(feature = "api-serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    #[b(6)] auth: Option<Cow<'a, str>>,
+    /// The point in time (milliseconds since the Unix epoch) after which
+    /// the sender is no longer interested in a response.
+    #[cfg_attr(feature = "api-serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    #[n(7)] deadline: Option<u64>,
+    /// A client-generated key identifying a logical operation, which allows
+    /// servers to detect retransmissions of the same request.
+    #[cfg_attr(feature = "api-serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    #[b(8)] idempotency_key: Option<Cow<'a, str>>,
+    /// Opaque CBOR-encoded context which is carried along with the
+    /// request, e.g. a tenant ID or trace headers.
+    #[cfg_attr(feature = "api-serde", serde(default, borrow, skip_serializing_if = "Option::is_none"))]
+    #[b(9)] context: Option<CowBytes<'a>>
 }
 
 /// The response header.
 #[derive(Debug, Clone, Encode, Decode)]
+#[cfg_attr(feature = "api-serde", derive(Serialize, Deserialize))]
 #[rustfmt::skip]
 #[cbor(map)]
 pub struct Response {
@@ -54,7 +99,8 @@ pub struct Response {
     /// errors. Otherwise this tag will not be produced and is ignored during
     /// decoding if present.
     #[cfg(feature = "tag")]
-    #[n(0)] tag: TypeTag<9750358>,
+    #[cfg_attr(feature = "api-serde", serde(skip))]
+    #[n(0)] tag: ResponseTag,
     /// The response identifier.
     #[n(1)] id: Id,
     /// The identifier of the request corresponding to this response.
@@ -115,13 +161,20 @@ pub fn internal_error<'a>(r: &'a Request, msg: &'a str) -> ResponseBuilder<Error
     Response::internal_error(r.id()).body(e)
 }
 
+#[cfg(feature = "std")]
+fn unix_millis(t: std::time::SystemTime) -> u64 {
+    t.duration_since(std::time::UNIX_EPOCH)
+        .map(|d| u64::try_from(d.as_millis()).unwrap_or(u64::MAX))
+        .unwrap_or(0)
+}
+
 /// A request/response identifier.
-#[derive(Debug, Default, Copy, Clone, Encode, Decode, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Default, Copy, Clone, Encode, Decode, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cbor(transparent)]
 pub struct Id(#[n(0)] u32);
 
 /// Request methods.
-#[derive(Debug, Copy, Clone, Encode, Decode)]
+#[derive(Debug, Copy, Clone, Encode, Decode, PartialEq, Eq, Hash)]
 #[rustfmt::skip]
 #[cbor(index_only)]
 pub enum Method {
@@ -129,61 +182,497 @@ pub enum Method {
     #[n(1)] Post,
     #[n(2)] Put,
     #[n(3)] Delete,
-    #[n(4)] Patch
+    #[n(4)] Patch,
+    #[n(5)] Head,
+    #[n(6)] Options
 }
 
-impl Display for Method {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        f.write_str(match self {
+impl Method {
+    /// The numeric wire value of this method, e.g. for metrics.
+    #[inline]
+    pub fn as_u8(self) -> u8 {
+        u8::from(self)
+    }
+
+    /// Is this a safe method, i.e. one that does not modify server state?
+    ///
+    /// Only GET and HEAD are; all other methods, including OPTIONS, are
+    /// conservatively treated as unsafe.
+    ///
+    /// See [RFC 7231, section 4.2.1](https://www.rfc-editor.org/rfc/rfc7231#section-4.2.1).
+    #[must_use]
+    pub fn is_safe(&self) -> bool {
+        matches!(self, Method::Get | Method::Head)
+    }
+
+    /// Is this an idempotent method, i.e. one that may be repeated with the
+    /// same effect as a single request?
+    ///
+    /// Only GET, HEAD, PUT and DELETE are, so OPTIONS requests are never
+    /// retried automatically.
+    ///
+    /// See [RFC 7231, section 4.2.2](https://www.rfc-editor.org/rfc/rfc7231#section-4.2.2).
+    #[must_use]
+    pub fn is_idempotent(&self) -> bool {
+        matches!(
+            self,
+            Method::Get | Method::Head | Method::Put | Method::Delete
+        )
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
             Self::Get => "GET",
             Self::Post => "POST",
             Self::Put => "PUT",
             Self::Delete => "DELETE",
             Self::Patch => "PATCH",
-        })
+            Self::Head => "HEAD",
+            Self::Options => "OPTIONS",
+        }
+    }
+}
+
+impl Display for Method {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Method {
+    type Err = ParseMethodError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let m = [
+            Method::Get,
+            Method::Post,
+            Method::Put,
+            Method::Delete,
+            Method::Patch,
+            Method::Head,
+            Method::Options,
+        ];
+        m.into_iter()
+            .find(|m| m.as_str().eq_ignore_ascii_case(s))
+            .ok_or(ParseMethodError(()))
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Method {
+    type Error = UnknownMethodName;
+
+    /// Like [`str::parse`], but the error retains the unknown name.
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        s.parse().map_err(|_| UnknownMethodName(s.into()))
+    }
+}
+
+impl TryFrom<String> for Method {
+    type Error = UnknownMethodName;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse().map_err(|_| UnknownMethodName(s))
+    }
+}
+
+#[cfg(feature = "api-serde")]
+impl Serialize for Method {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "api-serde")]
+impl<'de> Deserialize<'de> for Method {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(d)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+impl TryFrom<u8> for Method {
+    type Error = UnknownMethod;
+
+    fn try_from(n: u8) -> Result<Self, Self::Error> {
+        match n {
+            0 => Ok(Method::Get),
+            1 => Ok(Method::Post),
+            2 => Ok(Method::Put),
+            3 => Ok(Method::Delete),
+            4 => Ok(Method::Patch),
+            5 => Ok(Method::Head),
+            6 => Ok(Method::Options),
+            _ => Err(UnknownMethod(n)),
+        }
+    }
+}
+
+impl From<Method> for u8 {
+    fn from(m: Method) -> Self {
+        match m {
+            Method::Get => 0,
+            Method::Post => 1,
+            Method::Put => 2,
+            Method::Delete => 3,
+            Method::Patch => 4,
+            Method::Head => 5,
+            Method::Options => 6,
+        }
+    }
+}
+
+impl PartialEq<u8> for Method {
+    fn eq(&self, other: &u8) -> bool {
+        self.as_u8() == *other
+    }
+}
+
+impl PartialEq<Method> for u8 {
+    fn eq(&self, other: &Method) -> bool {
+        *self == other.as_u8()
     }
 }
 
 /// The response status codes.
+///
+/// Status codes are ordered by their numeric value, e.g. to check for
+/// client errors with `Status::BadRequest <= s && s < Status::InternalServerError`.
+/// Variants must therefore be declared in ascending numeric order.
 #[derive(Debug, Copy, Clone, Encode, Decode, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[non_exhaustive]
 #[rustfmt::skip]
 #[cbor(index_only)]
 pub enum Status {
     #[n(200)] Ok,
+    #[n(201)] Created,
+    #[n(202)] Accepted,
+    #[n(204)] NoContent,
     #[n(400)] BadRequest,
     #[n(401)] Unauthorized,
     #[n(403)] Forbidden,
     #[n(404)] NotFound,
-    #[n(409)] Conflict,
     #[n(405)] MethodNotAllowed,
+    #[n(409)] Conflict,
+    #[n(410)] Gone,
+    #[n(412)] PreconditionFailed,
+    #[n(422)] UnprocessableEntity,
+    #[n(423)] Locked,
+    #[n(429)] TooManyRequests,
     #[n(500)] InternalServerError,
-    #[n(501)] NotImplemented
+    #[n(501)] NotImplemented,
+    #[n(503)] ServiceUnavailable,
+    #[n(504)] GatewayTimeout
+}
+
+impl Status {
+    /// The numeric status code, e.g. for metrics.
+    #[inline]
+    pub fn as_u16(self) -> u16 {
+        u16::from(self)
+    }
+
+    /// Is this a 2xx status code?
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&u16::from(*self))
+    }
+
+    /// Is this a 4xx status code?
+    pub fn is_client_error(&self) -> bool {
+        (400..500).contains(&u16::from(*self))
+    }
+
+    /// Is this a 5xx status code?
+    pub fn is_server_error(&self) -> bool {
+        (500..600).contains(&u16::from(*self))
+    }
+
+    /// The canonical reason phrase of this status code, e.g. "Not Found".
+    pub fn reason_phrase(&self) -> &'static str {
+        match self {
+            Status::Ok => "OK",
+            Status::Created => "Created",
+            Status::Accepted => "Accepted",
+            Status::NoContent => "No Content",
+            Status::BadRequest => "Bad Request",
+            Status::Unauthorized => "Unauthorized",
+            Status::Forbidden => "Forbidden",
+            Status::NotFound => "Not Found",
+            Status::Conflict => "Conflict",
+            Status::Gone => "Gone",
+            Status::PreconditionFailed => "Precondition Failed",
+            Status::MethodNotAllowed => "Method Not Allowed",
+            Status::UnprocessableEntity => "Unprocessable Entity",
+            Status::Locked => "Locked",
+            Status::TooManyRequests => "Too Many Requests",
+            Status::InternalServerError => "Internal Server Error",
+            Status::NotImplemented => "Not Implemented",
+            Status::ServiceUnavailable => "Service Unavailable",
+            Status::GatewayTimeout => "Gateway Timeout",
+        }
+    }
 }
 
 impl Display for Status {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        f.write_str(match self {
-            Status::Ok => "200 Ok",
-            Status::BadRequest => "400 BadRequest",
-            Status::Unauthorized => "401 Unauthorized",
-            Status::Forbidden => "403 Forbidden",
-            Status::NotFound => "404 NotFound",
-            Status::Conflict => "409 Conflict",
-            Status::MethodNotAllowed => "405 MethodNotAllowed",
-            Status::InternalServerError => "500 InternalServerError",
-            Status::NotImplemented => "501 NotImplemented",
-        })
+        write!(f, "{} {}", u16::from(*self), self.reason_phrase())
+    }
+}
+
+impl FromStr for Status {
+    type Err = ParseStatusError;
+
+    /// Parse a status from its numeric code, optionally followed by
+    /// the reason phrase, e.g. "404" or "404 Not Found", or from the
+    /// reason phrase alone, ignoring ASCII case, e.g. "not found".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let code = s.split_whitespace().next().unwrap_or_default();
+        if let Ok(code) = code.parse::<u16>() {
+            return Status::try_from(code).map_err(|_| ParseStatusError(()));
+        }
+        (100..600)
+            .filter_map(|n| Status::try_from(n).ok())
+            .find(|status| status.reason_phrase().eq_ignore_ascii_case(s))
+            .ok_or(ParseStatusError(()))
+    }
+}
+
+impl TryFrom<u16> for Status {
+    type Error = UnknownStatus;
+
+    fn try_from(n: u16) -> Result<Self, Self::Error> {
+        match n {
+            200 => Ok(Status::Ok),
+            201 => Ok(Status::Created),
+            202 => Ok(Status::Accepted),
+            204 => Ok(Status::NoContent),
+            400 => Ok(Status::BadRequest),
+            401 => Ok(Status::Unauthorized),
+            403 => Ok(Status::Forbidden),
+            404 => Ok(Status::NotFound),
+            405 => Ok(Status::MethodNotAllowed),
+            409 => Ok(Status::Conflict),
+            410 => Ok(Status::Gone),
+            412 => Ok(Status::PreconditionFailed),
+            422 => Ok(Status::UnprocessableEntity),
+            423 => Ok(Status::Locked),
+            429 => Ok(Status::TooManyRequests),
+            500 => Ok(Status::InternalServerError),
+            501 => Ok(Status::NotImplemented),
+            503 => Ok(Status::ServiceUnavailable),
+            504 => Ok(Status::GatewayTimeout),
+            _ => Err(UnknownStatus(n)),
+        }
+    }
+}
+
+/// The response status corresponding to an error [`Kind`].
+///
+/// Kinds without a more specific status map to
+/// [`Status::InternalServerError`].
+impl From<Kind> for Status {
+    fn from(k: Kind) -> Self {
+        match k {
+            Kind::Invalid | Kind::Serialization => Status::BadRequest,
+            Kind::NotFound => Status::NotFound,
+            Kind::AlreadyExists | Kind::Conflict => Status::Conflict,
+            Kind::ResourceExhausted => Status::TooManyRequests,
+            Kind::Unsupported => Status::NotImplemented,
+            Kind::Shutdown => Status::ServiceUnavailable,
+            Kind::Timeout => Status::GatewayTimeout,
+            _ => Status::InternalServerError,
+        }
+    }
+}
+
+#[cfg(feature = "api-serde")]
+impl Serialize for Status {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_u16(u16::from(*self))
+    }
+}
+
+#[cfg(feature = "api-serde")]
+impl<'de> Deserialize<'de> for Status {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let n = u16::deserialize(d)?;
+        Status::try_from(n).map_err(de::Error::custom)
+    }
+}
+
+impl From<Status> for u16 {
+    fn from(s: Status) -> Self {
+        match s {
+            Status::Ok => 200,
+            Status::Created => 201,
+            Status::Accepted => 202,
+            Status::NoContent => 204,
+            Status::BadRequest => 400,
+            Status::Unauthorized => 401,
+            Status::Forbidden => 403,
+            Status::NotFound => 404,
+            Status::MethodNotAllowed => 405,
+            Status::Conflict => 409,
+            Status::Gone => 410,
+            Status::PreconditionFailed => 412,
+            Status::UnprocessableEntity => 422,
+            Status::Locked => 423,
+            Status::TooManyRequests => 429,
+            Status::InternalServerError => 500,
+            Status::NotImplemented => 501,
+            Status::ServiceUnavailable => 503,
+            Status::GatewayTimeout => 504,
+        }
+    }
+}
+
+/// Compare with a numeric status code, e.g. from an HTTP library.
+impl PartialEq<u16> for Status {
+    fn eq(&self, other: &u16) -> bool {
+        self.as_u16() == *other
+    }
+}
+
+impl PartialEq<Status> for u16 {
+    fn eq(&self, other: &Status) -> bool {
+        *self == other.as_u16()
     }
 }
 
+/// Error returned when parsing a request method from a string fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseMethodError(());
+
+impl Display for ParseMethodError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("unknown request method")
+    }
+}
+
+impl crate::compat::error::Error for ParseMethodError {}
+
+/// Error returned when parsing a response status from a string fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseStatusError(());
+
+impl Display for ParseStatusError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("unknown status code or reason phrase")
+    }
+}
+
+impl crate::compat::error::Error for ParseStatusError {}
+
+/// Error returned when a numeric value does not denote a known request method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownMethod(pub u8);
+
+impl Display for UnknownMethod {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown request method: {}", self.0)
+    }
+}
+
+impl crate::compat::error::Error for UnknownMethod {}
+
+/// Error returned when a string does not denote a known request method.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownMethodName(pub String);
+
+impl Display for UnknownMethodName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown request method: {}", self.0)
+    }
+}
+
+impl crate::compat::error::Error for UnknownMethodName {}
+
+/// Error returned when a numeric value does not denote a known status code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownStatus(pub u16);
+
+impl Display for UnknownStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown status code: {}", self.0)
+    }
+}
+
+impl crate::compat::error::Error for UnknownStatus {}
+
 impl Id {
+    /// The all-zero identifier, e.g. for responses without a corresponding request.
+    pub const ZERO: Id = Id(0);
+
+    pub fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+
     pub fn fresh() -> Self {
         // Ensure random Ids are not equal to 0 (the default Id):
         Id(rand::random::<u32>().saturating_add(1))
     }
+
+    /// Parse the hexadecimal representation produced by `Display`,
+    /// i.e. exactly 8 hex digits in any case.
+    pub fn from_hex(s: &str) -> Result<Self, IdParseError> {
+        if s.len() != 8 {
+            return Err(IdParseError::InvalidLength(s.len()));
+        }
+        if !s.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(IdParseError::InvalidDigit);
+        }
+        u32::from_str_radix(s, 16)
+            .map(Id)
+            .map_err(|_| IdParseError::InvalidDigit)
+    }
+}
+
+impl FromStr for Id {
+    type Err = IdParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Id::from_hex(s)
+    }
+}
+
+#[cfg(feature = "api-serde")]
+impl Serialize for Id {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.collect_str(self)
+    }
+}
+
+#[cfg(feature = "api-serde")]
+impl<'de> Deserialize<'de> for Id {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(d)?;
+        Id::from_hex(&s).map_err(de::Error::custom)
+    }
+}
+
+/// Error returned when parsing an [`Id`] from a string fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum IdParseError {
+    /// The input does not consist of exactly 8 characters.
+    InvalidLength(usize),
+    /// The input contains a character which is not a hex digit.
+    InvalidDigit,
+}
+
+impl Display for IdParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            IdParseError::InvalidLength(n) => {
+                write!(f, "invalid id length (expected 8 hex digits, got {n})")
+            }
+            IdParseError::InvalidDigit => f.write_str("invalid id: not a hex digit"),
+        }
+    }
 }
 
+impl crate::compat::error::Error for IdParseError {}
+
 impl From<Id> for u32 {
     fn from(n: Id) -> Self {
         n.0
@@ -191,11 +680,78 @@ impl From<Id> for u32 {
 }
 
 impl Display for Id {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(self, f)
+    }
+}
+
+/// The canonical representation of an [`Id`]: 8 lowercase hex digits.
+impl fmt::LowerHex for Id {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(f, "{:08x}", self.0)
     }
 }
 
+impl fmt::UpperHex for Id {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:08X}", self.0)
+    }
+}
+
+impl fmt::Binary for Id {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:032b}", self.0)
+    }
+}
+
+type RequestTag = TypeTag<7586022>;
+type ResponseTag = TypeTag<9750358>;
+type ErrorTag = TypeTag<5359172>;
+
+impl Tagged for Request<'_> {
+    const TAG: usize = RequestTag::VALUE;
+}
+
+impl Tagged for Response {
+    const TAG: usize = ResponseTag::VALUE;
+}
+
+impl Tagged for Error<'_> {
+    const TAG: usize = ErrorTag::VALUE;
+}
+
+/// A one-line summary, e.g. "GET /nodes/foo (id=0a1b2c3d)".
+impl Display for Request<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} (id={})", self.method_str(), self.path, self.id)
+    }
+}
+
+/// Extract the method and the owned path segments, e.g. for dispatching.
+///
+/// Fails with a "400 Bad Request" error if the method is unknown.
+impl<'a> TryFrom<Request<'a>> for (Method, Vec<String>) {
+    type Error = Error<'a>;
+
+    fn try_from(req: Request<'a>) -> Result<Self, Self::Error> {
+        let method = match req.method {
+            Some(m) => m,
+            None => {
+                return Err(Error::new(req.path)
+                    .with_message("unknown method")
+                    .with_status(Status::BadRequest))
+            }
+        };
+        let segments = req
+            .path
+            .trim_start_matches('/')
+            .split('/')
+            .map(String::from)
+            .collect();
+        Ok((method, segments))
+    }
+}
+
 impl<'a> Request<'a> {
     pub fn new<P: Into<Cow<'a, str>>>(method: Method, path: P, has_body: bool) -> Self {
         Request {
@@ -205,6 +761,11 @@ impl<'a> Request<'a> {
             method: Some(method),
             path: path.into(),
             has_body,
+            query: None,
+            auth: None,
+            deadline: None,
+            idempotency_key: None,
+            context: None,
         }
     }
 
@@ -212,6 +773,12 @@ impl<'a> Request<'a> {
         RequestBuilder {
             header: Request::new(method, path, false),
             body: None,
+            explicit: Explicit {
+                id: false,
+                method: true,
+                path: true,
+            },
+            state: PhantomData,
         }
     }
 
@@ -235,6 +802,14 @@ impl<'a> Request<'a> {
         Request::builder(Method::Patch, path)
     }
 
+    pub fn head<P: Into<Cow<'a, str>>>(path: P) -> RequestBuilder<'a> {
+        Request::builder(Method::Head, path)
+    }
+
+    pub fn options<P: Into<Cow<'a, str>>>(path: P) -> RequestBuilder<'a> {
+        Request::builder(Method::Options, path)
+    }
+
     pub fn id(&self) -> Id {
         self.id
     }
@@ -243,20 +818,127 @@ impl<'a> Request<'a> {
         &*self.path
     }
 
+    /// Is a response with the given `re` identifier a response to this request?
+    pub fn expects_response_id(&self, re: Id) -> bool {
+        self.id == re
+    }
+
     pub fn path_segments<const N: usize>(&self) -> Segments<N> {
         Segments::parse(self.path())
     }
 
+    /// Like [`Request::path_segments`] but splits the path lazily.
+    pub fn path_segments_iter<const N: usize>(&self) -> SegmentsIter<'_> {
+        SegmentsIter::new(self.path(), N)
+    }
+
     pub fn method(&self) -> Option<Method> {
         self.method
     }
 
+    /// The method name of this request, or `"UNKNOWN"` if not recognised.
+    pub fn method_str(&self) -> &'static str {
+        self.method
+            .as_ref()
+            .map(Method::as_str)
+            .unwrap_or("UNKNOWN")
+    }
+
     pub fn has_body(&self) -> bool {
         self.has_body
     }
+
+    pub fn query(&self) -> Option<&str> {
+        self.query.as_deref()
+    }
+
+    /// The bearer token of this request, if any.
+    pub fn auth_token(&self) -> Option<&str> {
+        self.auth.as_deref()
+    }
+
+    /// The idempotency key of this request, if any.
+    pub fn idempotency_key(&self) -> Option<&str> {
+        self.idempotency_key.as_deref()
+    }
+
+    /// The CBOR-encoded context of this request, if any.
+    ///
+    /// See [`RequestBuilder::with_context`].
+    pub fn context_bytes(&self) -> Option<&[u8]> {
+        self.context.as_deref()
+    }
+
+    /// The deadline of this request in milliseconds since the Unix epoch.
+    pub fn deadline(&self) -> Option<u64> {
+        self.deadline
+    }
+
+    /// Check if the deadline (if any) has passed at the given time,
+    /// given in milliseconds since the Unix epoch.
+    pub fn is_expired_at(&self, now: u64) -> bool {
+        matches!(self.deadline, Some(d) if now > d)
+    }
+
+    /// Check if the deadline (if any) has passed.
+    #[cfg(feature = "std")]
+    pub fn is_expired(&self) -> bool {
+        self.is_expired_at(unix_millis(std::time::SystemTime::now()))
+    }
+
+    /// Decode the request body from the bytes following this header.
+    ///
+    /// Fails if this header indicates that there is no body.
+    pub fn decode_body<'b, T: Decode<'b, ()>>(&self, tail: &'b [u8]) -> Result<T, decode::Error> {
+        if !self.has_body {
+            return Err(decode::Error::message("request has no body"));
+        }
+        decode(tail)
+    }
+
+    /// Convert into an owned request header, cloning borrowed data if necessary.
+    pub fn into_owned(self) -> Request<'static> {
+        Request {
+            #[cfg(feature = "tag")]
+            tag: self.tag,
+            id: self.id,
+            path: Cow::Owned(self.path.into_owned()),
+            method: self.method,
+            has_body: self.has_body,
+            query: self.query.map(|q| Cow::Owned(q.into_owned())),
+            auth: self.auth.map(|a| Cow::Owned(a.into_owned())),
+            deadline: self.deadline,
+            idempotency_key: self.idempotency_key.map(|k| Cow::Owned(k.into_owned())),
+            context: self.context.map(CowBytes::into_static),
+        }
+    }
+
+    /// Iterate over the '&'-separated key-value pairs of the query string.
+    ///
+    /// A parameter without '=' is returned with an empty value.
+    pub fn query_params(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.query()
+            .unwrap_or_default()
+            .split('&')
+            .filter(|p| !p.is_empty())
+            .map(|p| p.split_once('=').unwrap_or((p, "")))
+    }
+}
+
+/// A one-line summary, e.g. "200 OK re=0a1b2c3d (id=0e1f2031)".
+impl Display for Response {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.status {
+            Some(s) => write!(f, "{s} re={} (id={})", self.re, self.id),
+            None => write!(f, "??? re={} (id={})", self.re, self.id),
+        }
+    }
 }
 
 impl Response {
+    /// Create a new response header to the request with the given identifier.
+    ///
+    /// By convention, unsolicited responses use [`Id::ZERO`] for `re`.
     pub fn new(re: Id, status: Status, has_body: bool) -> Self {
         Response {
             #[cfg(feature = "tag")]
@@ -272,6 +954,7 @@ impl Response {
         ResponseBuilder {
             header: Response::new(re, status, false),
             body: None,
+            method: None,
         }
     }
 
@@ -279,6 +962,21 @@ impl Response {
         Response::builder(re, Status::Ok)
     }
 
+    pub fn created(re: Id) -> ResponseBuilder {
+        Response::builder(re, Status::Created)
+    }
+
+    pub fn accepted(re: Id) -> ResponseBuilder {
+        Response::builder(re, Status::Accepted)
+    }
+
+    /// A response without a body.
+    ///
+    /// Attaching a body to this response is a programming error.
+    pub fn no_content(re: Id) -> ResponseBuilder {
+        Response::builder(re, Status::NoContent)
+    }
+
     pub fn bad_request(re: Id) -> ResponseBuilder {
         Response::builder(re, Status::BadRequest)
     }
@@ -287,6 +985,11 @@ impl Response {
         Response::builder(re, Status::NotFound)
     }
 
+    /// An error response with the status of the given error.
+    pub fn from_error<'a>(re: Id, err: &Error<'a>) -> ResponseBuilder<Error<'a>> {
+        Response::builder(re, err.inferred_status()).body(err.clone())
+    }
+
     pub fn not_implemented(re: Id) -> ResponseBuilder {
         Response::builder(re, Status::NotImplemented)
     }
@@ -299,10 +1002,48 @@ impl Response {
         Response::builder(re, Status::Forbidden)
     }
 
+    pub fn conflict(re: Id) -> ResponseBuilder {
+        Response::builder(re, Status::Conflict)
+    }
+
+    pub fn gone(re: Id) -> ResponseBuilder {
+        Response::builder(re, Status::Gone)
+    }
+
+    pub fn precondition_failed(re: Id) -> ResponseBuilder {
+        Response::builder(re, Status::PreconditionFailed)
+    }
+
+    pub fn unprocessable_entity(re: Id) -> ResponseBuilder {
+        Response::builder(re, Status::UnprocessableEntity)
+    }
+
+    pub fn locked(re: Id) -> ResponseBuilder {
+        Response::builder(re, Status::Locked)
+    }
+
+    pub fn too_many_requests(re: Id) -> ResponseBuilder {
+        Response::builder(re, Status::TooManyRequests)
+    }
+
     pub fn internal_error(re: Id) -> ResponseBuilder {
         Response::builder(re, Status::InternalServerError)
     }
 
+    pub fn service_unavailable(re: Id) -> ResponseBuilder {
+        Response::builder(re, Status::ServiceUnavailable)
+    }
+
+    /// A "service unavailable" response with the number of seconds after
+    /// which the request may be retried as body.
+    pub fn service_unavailable_retry_after(re: Id, seconds: u32) -> ResponseBuilder<u32> {
+        Response::service_unavailable(re).body(seconds)
+    }
+
+    pub fn gateway_timeout(re: Id) -> ResponseBuilder {
+        Response::builder(re, Status::GatewayTimeout)
+    }
+
     pub fn id(&self) -> Id {
         self.id
     }
@@ -311,17 +1052,76 @@ impl Response {
         self.re
     }
 
+    /// Is this the response to the given request?
+    pub fn correlates_to(&self, req: &Request) -> bool {
+        self.re == req.id
+    }
+
     pub fn status(&self) -> Option<Status> {
         self.status
     }
 
+    /// Does this response have a 2xx status code?
+    ///
+    /// Unknown status codes are not considered successful.
+    pub fn is_success(&self) -> bool {
+        matches!(self.status, Some(s) if s.is_success())
+    }
+
+    /// Does this response have a 4xx or 5xx status code?
+    pub fn is_error(&self) -> bool {
+        self.is_client_error() || self.is_server_error()
+    }
+
+    /// Does this response have a 4xx status code?
+    pub fn is_client_error(&self) -> bool {
+        matches!(self.status, Some(s) if s.is_client_error())
+    }
+
+    /// Does this response have a 5xx status code?
+    pub fn is_server_error(&self) -> bool {
+        matches!(self.status, Some(s) if s.is_server_error())
+    }
+
     pub fn has_body(&self) -> bool {
         self.has_body
     }
+
+    /// Decode the response body from the bytes following this header.
+    ///
+    /// Fails if this header indicates that there is no body.
+    pub fn decode_body<'b, T: Decode<'b, ()>>(&self, tail: &'b [u8]) -> Result<T, decode::Error> {
+        if !self.has_body {
+            return Err(decode::Error::message("response has no body"));
+        }
+        decode(tail)
+    }
+
+    /// Decode the response body as `T` if the status indicates success
+    /// and as [`Error`] otherwise.
+    ///
+    /// If the body can not be decoded, a synthetic error describing the
+    /// failure is returned.
+    pub fn into_result<'b, T: Decode<'b, ()>>(self, body: &'b [u8]) -> Result<T, Error<'static>> {
+        let synthetic = |msg: String| Error::default().with_message(msg);
+        if self.is_success() {
+            return self
+                .decode_body(body)
+                .map_err(|e| synthetic(format!("failed to decode response body: {e}")));
+        }
+        match self.decode_body::<Error>(body) {
+            Ok(e) => Err(e.into_owned()),
+            Err(_) => match self.status {
+                Some(s) => Err(synthetic(format!("{s}"))),
+                None => Err(synthetic(String::from("unknown status"))),
+            },
+        }
+    }
 }
 
 /// An error type used in response bodies.
 #[derive(Debug, Clone, Default, Encode, Decode)]
+#[cfg_attr(feature = "api-serde", derive(Serialize, Deserialize))]
 #[rustfmt::skip]
 #[cbor(map)]
 pub struct Error<'a> {
@@ -332,113 +1132,510 @@ pub struct Error<'a> {
     /// errors. Otherwise this tag will not be produced and is ignored during
     /// decoding if present.
     #[cfg(feature = "tag")]
-    #[n(0)] tag: TypeTag<5359172>,
+    #[cfg_attr(feature = "api-serde", serde(skip))]
+    #[n(0)] tag: ErrorTag,
     /// The resource path of this error.
+    #[cfg_attr(feature = "api-serde", serde(default, skip_serializing_if = "Option::is_none"))]
     #[b(1)] path: Option<Cow<'a, str>>,
     /// The request method of this error.
+    #[cfg_attr(feature = "api-serde", serde(default, skip_serializing_if = "Option::is_none"))]
     #[n(2)] method: Option<Method>,
     /// The actual error message.
+    #[cfg_attr(feature = "api-serde", serde(default, skip_serializing_if = "Option::is_none"))]
     #[b(3)] message: Option<Cow<'a, str>>,
+    /// Additional, more technical details about the error.
+    #[cfg_attr(feature = "api-serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    #[b(4)] detail: Option<Cow<'a, str>>,
+    /// The intended response status.
+    #[cfg_attr(feature = "api-serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    #[n(5)] status: Option<Status>,
 }
 
-impl<'a> Error<'a> {
-    pub fn new<S: Into<Cow<'a, str>>>(path: S) -> Self {
-        Error {
-            #[cfg(feature = "tag")]
-            tag: TypeTag,
-            method: None,
-            path: Some(path.into()),
-            message: None,
-        }
+impl<'a> Error<'a> {
+    pub fn new<S: Into<Cow<'a, str>>>(path: S) -> Self {
+        Error {
+            #[cfg(feature = "tag")]
+            tag: TypeTag,
+            method: None,
+            path: Some(path.into()),
+            message: None,
+            detail: None,
+            status: None,
+        }
+    }
+
+    pub fn build<S: Into<Cow<'a, str>>>(path: S) -> ErrorBuilder<'a> {
+        ErrorBuilder {
+            error: Error::new(path),
+            kind: None,
+        }
+    }
+
+    pub fn with_method(mut self, m: Method) -> Self {
+        self.method = Some(m);
+        self
+    }
+
+    pub fn with_message<S: Into<Cow<'a, str>>>(mut self, m: S) -> Self {
+        self.message = Some(m.into());
+        self
+    }
+
+    pub fn with_detail<S: Into<Cow<'a, str>>>(mut self, d: S) -> Self {
+        self.detail = Some(d.into());
+        self
+    }
+
+    pub fn with_status(mut self, s: Status) -> Self {
+        self.status = Some(s);
+        self
+    }
+
+    pub fn path(&self) -> Option<&str> {
+        self.path.as_deref()
+    }
+
+    pub fn method(&self) -> Option<Method> {
+        self.method
+    }
+
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    pub fn detail(&self) -> Option<&str> {
+        self.detail.as_deref()
+    }
+
+    pub fn status(&self) -> Option<Status> {
+        self.status
+    }
+
+    /// The intended response status, defaulting to [`Status::InternalServerError`].
+    pub fn inferred_status(&self) -> Status {
+        self.status.unwrap_or(Status::InternalServerError)
+    }
+
+    /// Convert into an owned error, cloning borrowed data if necessary.
+    pub fn into_owned(self) -> Error<'static> {
+        Error {
+            #[cfg(feature = "tag")]
+            tag: self.tag,
+            path: self.path.map(|p| Cow::Owned(p.into_owned())),
+            method: self.method,
+            message: self.message.map(|m| Cow::Owned(m.into_owned())),
+            detail: self.detail.map(|d| Cow::Owned(d.into_owned())),
+            status: self.status,
+        }
+    }
+}
+
+impl Display for Error<'_> {
+    /// Formats the error as "[METHOD] PATH: MESSAGE", omitting method and
+    /// message if not present.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(m) = self.method {
+            write!(f, "[{m}] ")?
+        }
+        f.write_str(self.path().unwrap_or_default())?;
+        if let Some(m) = self.message() {
+            write!(f, ": {m}")?
+        }
+        Ok(())
+    }
+}
+
+impl crate::compat::error::Error for Error<'_> {}
+
+impl From<Error<'_>> for crate::Error {
+    fn from(e: Error<'_>) -> Self {
+        crate::Error::new(Origin::Application, Kind::Protocol, format!("{e}"))
+    }
+}
+
+/// Path segments, i.e. '/'-separated string slices.
+pub struct Segments<'a, const N: usize>(ArrayVec<[&'a str; N]>);
+
+impl<'a, const N: usize> Segments<'a, N> {
+    pub fn parse(s: &'a str) -> Self {
+        Self(SegmentsIter::new(s, N).collect())
+    }
+
+    pub fn as_slice(&self) -> &[&'a str] {
+        &self.0[..]
+    }
+
+    pub fn iter(&self) -> SegmentsIter<'_> {
+        SegmentsIter(IterImpl::Parsed(self.0.iter()))
+    }
+
+    /// The first segment, if any.
+    pub fn head(&self) -> Option<&'a str> {
+        self.get(0)
     }
 
-    pub fn with_method(mut self, m: Method) -> Self {
-        self.method = Some(m);
-        self
+    /// All segments after the first one.
+    pub fn tail(&self) -> Self {
+        Self(self.0.iter().skip(1).copied().collect())
     }
 
-    pub fn with_message<S: Into<Cow<'a, str>>>(mut self, m: S) -> Self {
-        self.message = Some(m.into());
-        self
+    pub fn get(&self, i: usize) -> Option<&'a str> {
+        self.0.get(i).copied()
     }
 
-    pub fn path(&self) -> Option<&str> {
-        self.path.as_deref()
+    pub fn len(&self) -> usize {
+        self.0.len()
     }
 
-    pub fn method(&self) -> Option<Method> {
-        self.method
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
     }
 
-    pub fn message(&self) -> Option<&str> {
-        self.message.as_deref()
+    /// Check if the leading segments are equal to the given prefix.
+    pub fn starts_with(&self, prefix: &[&str]) -> bool {
+        self.len() >= prefix.len() && self.0.iter().zip(prefix).all(|(a, b)| a == b)
+    }
+
+    /// Remove the given prefix and return the remaining segments.
+    ///
+    /// Returns `None` if the segments do not start with `prefix`.
+    pub fn strip_prefix(&self, prefix: &[&str]) -> Option<Self> {
+        if !self.starts_with(prefix) {
+            return None;
+        }
+        Some(Self(self.0[prefix.len()..].iter().copied().collect()))
+    }
+
+    /// Reconstruct a path from the segments, e.g. after stripping a prefix.
+    ///
+    /// The result always starts with `/` and empty segments (from doubled
+    /// slashes) are dropped.
+    pub fn join(&self) -> String {
+        let len = self.0.iter().map(|s| s.len() + 1).sum::<usize>().max(1);
+        let mut p = String::with_capacity(len);
+        for s in self.0.iter().filter(|s| !s.is_empty()) {
+            p.push('/');
+            p.push_str(s)
+        }
+        if p.is_empty() {
+            p.push('/')
+        }
+        p
     }
 }
 
-/// Path segments, i.e. '/'-separated string slices.
-pub struct Segments<'a, const N: usize>(ArrayVec<[&'a str; N]>);
+impl<'a, const N: usize> IntoIterator for Segments<'a, N> {
+    type Item = &'a str;
+    type IntoIter = tinyvec::ArrayVecIterator<[&'a str; N]>;
 
-impl<'a, const N: usize> Segments<'a, N> {
-    pub fn parse(s: &'a str) -> Self {
-        if s.starts_with('/') {
-            Self(s.trim_start_matches('/').splitn(N, '/').collect())
-        } else {
-            Self(s.splitn(N, '/').collect())
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, const N: usize> IntoIterator for &'a Segments<'_, N> {
+    type Item = &'a str;
+    type IntoIter = SegmentsIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over path segments.
+///
+/// When created with [`SegmentsIter::new`] the path is split on demand,
+/// so inspecting only the first few segments does not scan the rest.
+/// [`Segments::iter`] borrows the already parsed segments, without
+/// copying or allocating anything.
+pub struct SegmentsIter<'a>(IterImpl<'a>);
+
+enum IterImpl<'a> {
+    /// Splits the path as we go.
+    Lazy(core::str::SplitN<'a, char>),
+    /// Walks the segments of a [`Segments`] value in place.
+    Parsed(core::slice::Iter<'a, &'a str>),
+}
+
+impl<'a> SegmentsIter<'a> {
+    /// Split `path` into at most `n` segments, ignoring leading slashes.
+    ///
+    /// The last segment contains the unsplit remainder of the path.
+    pub fn new(path: &'a str, n: usize) -> Self {
+        let path = path.trim_start_matches('/');
+        SegmentsIter(IterImpl::Lazy(path.splitn(n, '/')))
+    }
+}
+
+impl<'a> Iterator for SegmentsIter<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.0 {
+            IterImpl::Lazy(i) => i.next(),
+            IterImpl::Parsed(i) => i.next().copied(),
         }
     }
 
-    pub fn as_slice(&self) -> &[&'a str] {
-        &self.0[..]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.0 {
+            IterImpl::Lazy(i) => i.size_hint(),
+            IterImpl::Parsed(i) => i.size_hint(),
+        }
     }
 }
 
+/// Concatenate two paths, separated by exactly one `/`.
+fn join_paths(base: &str, segment: &str) -> String {
+    let base = base.trim_end_matches('/');
+    let segment = segment.trim_start_matches('/');
+    let mut p = String::with_capacity(base.len() + segment.len() + 1);
+    p.push_str(base);
+    p.push('/');
+    p.push_str(segment);
+    p
+}
+
+/// Type state of a [`RequestBuilder`] whose request method is known.
+#[derive(Debug)]
+pub struct MethodSet;
+
+/// Type state of a [`RequestBuilder`] without request method.
+///
+/// Such a builder can not be encoded until a method has been set:
+///
+/// ```compile_fail
+/// use ockam_core::api::RequestBuilder;
+///
+/// RequestBuilder::new("/nodes").to_vec();
+/// ```
+///
+/// ```
+/// use ockam_core::api::{Method, RequestBuilder};
+///
+/// RequestBuilder::new("/nodes").method(Method::Get).to_vec().unwrap();
+/// ```
+#[derive(Debug)]
+pub struct MethodUnset;
+
 #[derive(Debug)]
-pub struct RequestBuilder<'a, T = ()> {
+pub struct RequestBuilder<'a, T = (), M = MethodSet> {
     header: Request<'a>,
     body: Option<T>,
+    explicit: Explicit,
+    state: PhantomData<M>,
+}
+
+/// The header fields of a [`RequestBuilder`] which have been set explicitly
+/// rather than defaulted (see [`RequestBuilder::merge`]).
+///
+/// The optional header fields are not tracked here as they are unset unless
+/// given explicitly.
+#[derive(Debug, Clone, Copy, Default)]
+struct Explicit {
+    id: bool,
+    method: bool,
+    path: bool,
+}
+
+/// An empty `GET /` request, e.g. for tests and fixtures.
+///
+/// ```
+/// use ockam_core::api::{Method, RequestBuilder};
+///
+/// let req = RequestBuilder::default()
+///     .path("/nodes")
+///     .method(Method::Post)
+///     .body("n1");
+/// assert_eq!("/nodes", req.header().path());
+/// assert_eq!(Some(Method::Post), req.header().method());
+/// ```
+impl Default for RequestBuilder<'_> {
+    fn default() -> Self {
+        let mut b = Request::get("/");
+        b.explicit = Explicit::default();
+        b
+    }
+}
+
+impl<'a> RequestBuilder<'a, (), MethodUnset> {
+    /// Start a request without method, which has to be set before encoding.
+    pub fn new<P: Into<Cow<'a, str>>>(path: P) -> Self {
+        let mut header = Request::new(Method::Get, path, false);
+        header.method = None;
+        RequestBuilder {
+            header,
+            body: None,
+            explicit: Explicit {
+                id: false,
+                method: false,
+                path: true,
+            },
+            state: PhantomData,
+        }
+    }
 }
 
-impl<'a, T> RequestBuilder<'a, T> {
+impl<'a, T, M> RequestBuilder<'a, T, M> {
     pub fn id(mut self, id: Id) -> Self {
         self.header.id = id;
+        self.explicit.id = true;
         self
     }
 
     pub fn path<P: Into<Cow<'a, str>>>(mut self, path: P) -> Self {
         self.header.path = path.into();
+        self.explicit.path = true;
         self
     }
 
-    pub fn method(mut self, m: Method) -> Self {
+    /// Append a segment to the path, separated by exactly one `/`.
+    pub fn path_append(mut self, segment: &str) -> Self {
+        self.header.path = Cow::Owned(join_paths(&self.header.path, segment));
+        self.explicit.path = true;
+        self
+    }
+
+    /// Prepend a prefix (e.g. `"/v0"`) to the path, separated by exactly one `/`.
+    pub fn path_prefix<P: Into<Cow<'a, str>>>(mut self, prefix: P) -> Self {
+        self.header.path = Cow::Owned(join_paths(&prefix.into(), &self.header.path));
+        self.explicit.path = true;
+        self
+    }
+
+    pub fn method(mut self, m: Method) -> RequestBuilder<'a, T, MethodSet> {
         self.header.method = Some(m);
+        RequestBuilder {
+            header: self.header,
+            body: self.body,
+            explicit: Explicit {
+                method: true,
+                ..self.explicit
+            },
+            state: PhantomData,
+        }
+    }
+
+    pub fn query<Q: Into<Cow<'a, str>>>(mut self, query: Q) -> Self {
+        self.header.query = Some(query.into());
         self
     }
 
+    /// Authorize the request with the given bearer token.
+    pub fn bearer_auth<A: Into<Cow<'a, str>>>(mut self, token: A) -> Self {
+        self.header.auth = Some(token.into());
+        self
+    }
+
+    /// Mark this request as a (re-)transmission of the operation identified by `key`.
+    pub fn idempotency_key(mut self, key: impl Into<CowStr<'a>>) -> Self {
+        self.header.idempotency_key = Some(key.into().into());
+        self
+    }
+
+    /// Attach an opaque context to this request.
+    ///
+    /// The context is encoded as CBOR and not interpreted by this API
+    /// layer. Receivers get the encoded bytes via [`Request::context_bytes`].
+    pub fn with_context<C: Encode<()>>(
+        mut self,
+        ctx: C,
+    ) -> Result<Self, encode::Error<<Vec<u8> as Write>::Error>> {
+        let bytes = minicbor::to_vec(ctx)?;
+        self.header.context = Some(CowBytes(Cow::Owned(bytes)));
+        Ok(self)
+    }
+
+    /// Set the point in time after which a response is no longer of interest.
+    #[cfg(feature = "std")]
+    pub fn deadline(mut self, t: std::time::SystemTime) -> Self {
+        self.header.deadline = Some(unix_millis(t));
+        self
+    }
+
+    /// Set the deadline to the given duration from now.
+    #[cfg(feature = "std")]
+    pub fn timeout(self, d: core::time::Duration) -> Self {
+        self.deadline(std::time::SystemTime::now() + d)
+    }
+
     pub fn header(&self) -> &Request<'a> {
         &self.header
     }
 
+    /// Call `f` with the current header, e.g. to log it mid-chain.
+    #[inline]
+    pub fn inspect<F: FnOnce(&Request<'a>)>(self, f: F) -> Self {
+        f(&self.header);
+        self
+    }
+
     pub fn into_parts(self) -> (Request<'a>, Option<T>) {
         (self.header, self.body)
     }
 }
 
-impl<'a> RequestBuilder<'a, ()> {
-    pub fn body<T: Encode<()>>(self, b: T) -> RequestBuilder<'a, T> {
+impl<'a, M> RequestBuilder<'a, (), M> {
+    pub fn body<T: Encode<()>>(self, b: T) -> RequestBuilder<'a, T, M> {
         let mut b = RequestBuilder {
             header: self.header,
             body: Some(b),
+            explicit: self.explicit,
+            state: PhantomData,
         };
         b.header.has_body = true;
         b
     }
 }
 
+impl<'a, T: Clone, M> RequestBuilder<'a, T, M> {
+    /// Combine this builder with `other`, whose values take precedence.
+    ///
+    /// Only values which have been set explicitly in `other` are taken,
+    /// i.e. the default path `/`, the random ID and the method of
+    /// [`RequestBuilder::default`] never replace ours. Body and optional
+    /// header fields (query, authorization, deadline, idempotency key and
+    /// context) are taken from `other` if present.
+    pub fn merge<N>(mut self, other: &RequestBuilder<'a, T, N>) -> Self {
+        let o = &other.header;
+        if other.explicit.path {
+            self.header.path = o.path.clone();
+            self.explicit.path = true
+        }
+        if other.explicit.id {
+            self.header.id = o.id;
+            self.explicit.id = true
+        }
+        if other.explicit.method {
+            self.header.method = o.method;
+            self.explicit.method = true
+        }
+        if let Some(b) = &other.body {
+            self.body = Some(b.clone());
+            self.header.has_body = true
+        }
+        self.header.query = o.query.clone().or(self.header.query);
+        self.header.auth = o.auth.clone().or(self.header.auth);
+        self.header.deadline = o.deadline.or(self.header.deadline);
+        self.header.idempotency_key = o.idempotency_key.clone().or(self.header.idempotency_key);
+        self.header.context = o.context.clone().or(self.header.context);
+        self
+    }
+}
+
 impl<'a, T: Encode<()>> RequestBuilder<'a, T> {
     pub fn encode<W>(&self, buf: W) -> Result<(), encode::Error<W::Error>>
     where
         W: Write,
     {
+        #[cfg(feature = "api-tracing")]
+        let _span = tracing::debug_span!(
+            "ockam_api::encode_request",
+            id = %self.header.id(),
+            method = %self.header.method_str(),
+            path = %self.header.path()
+        )
+        .entered();
         let mut e = Encoder::new(buf);
         e.encode(&self.header)?;
         if let Some(b) = &self.body {
@@ -447,18 +1644,44 @@ impl<'a, T: Encode<()>> RequestBuilder<'a, T> {
         Ok(())
     }
 
-    pub fn to_vec(self) -> Result<Vec<u8>, encode::Error<<Vec<u8> as Write>::Error>> {
+    /// Encode header and body (if any) into a new byte vector.
+    pub fn encode_to_vec(&self) -> Result<Vec<u8>, encode::Error<<Vec<u8> as Write>::Error>> {
         let mut buf = Vec::new();
         self.encode(&mut buf)?;
 
         Ok(buf)
     }
+
+    pub fn to_vec(self) -> Result<Vec<u8>, encode::Error<<Vec<u8> as Write>::Error>> {
+        self.encode_to_vec()
+    }
+
+    /// Encode header and body (if any) with the given codec, appending to `buf`.
+    pub fn encode_with<C>(&self, codec: &C, buf: &mut Vec<u8>) -> Result<(), CodecError>
+    where
+        C: CodecEncode<Request<'a>> + CodecEncode<T>,
+    {
+        buf.extend(codec.encode(&self.header)?);
+        if let Some(b) = &self.body {
+            buf.extend(codec.encode(b)?);
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug)]
 pub struct ResponseBuilder<T = ()> {
     header: Response,
     body: Option<T>,
+    /// The method of the request this is a response to, if known.
+    method: Option<Method>,
+}
+
+/// An empty `200 OK` response to [`Id::ZERO`], e.g. for tests and fixtures.
+impl Default for ResponseBuilder {
+    fn default() -> Self {
+        Response::ok(Id::ZERO)
+    }
 }
 
 impl<T> ResponseBuilder<T> {
@@ -477,10 +1700,25 @@ impl<T> ResponseBuilder<T> {
         self
     }
 
+    /// Set the method of the request this is a response to.
+    ///
+    /// Responses to HEAD requests must not have a body.
+    pub fn method(mut self, m: Method) -> Self {
+        self.method = Some(m);
+        self
+    }
+
     pub fn header(&self) -> &Response {
         &self.header
     }
 
+    /// Call `f` with the current header, e.g. to log it mid-chain.
+    #[inline]
+    pub fn inspect<F: FnOnce(&Response)>(self, f: F) -> Self {
+        f(&self.header);
+        self
+    }
+
     pub fn into_parts(self) -> (Response, Option<T>) {
         (self.header, self.body)
     }
@@ -488,9 +1726,18 @@ impl<T> ResponseBuilder<T> {
 
 impl ResponseBuilder<()> {
     pub fn body<T: Encode<()>>(self, b: T) -> ResponseBuilder<T> {
+        debug_assert! {
+            self.header.status != Some(Status::NoContent),
+            "204 responses must not have a body"
+        }
+        debug_assert! {
+            self.method != Some(Method::Head),
+            "responses to HEAD requests must not have a body"
+        }
         let mut b = ResponseBuilder {
             header: self.header,
             body: Some(b),
+            method: self.method,
         };
         b.header.has_body = true;
         b
@@ -510,6 +1757,89 @@ impl<T: Encode<()>> ResponseBuilder<T> {
         Ok(())
     }
 
+    /// Encode header and body (if any) into a new byte vector.
+    pub fn encode_to_vec(&self) -> Result<Vec<u8>, encode::Error<<Vec<u8> as Write>::Error>> {
+        let mut buf = Vec::new();
+        self.encode(&mut buf)?;
+
+        Ok(buf)
+    }
+
+    pub fn to_vec(self) -> Result<Vec<u8>, encode::Error<<Vec<u8> as Write>::Error>> {
+        self.encode_to_vec()
+    }
+}
+
+#[derive(Debug)]
+pub struct ErrorBuilder<'a> {
+    error: Error<'a>,
+    kind: Option<Kind>,
+}
+
+impl<'a> ErrorBuilder<'a> {
+    pub fn method(mut self, m: Method) -> Self {
+        self.error.method = Some(m);
+        self
+    }
+
+    pub fn message<S: Into<Cow<'a, str>>>(mut self, m: S) -> Self {
+        self.error.message = Some(m.into());
+        self
+    }
+
+    pub fn detail<S: Into<Cow<'a, str>>>(mut self, d: S) -> Self {
+        self.error.detail = Some(d.into());
+        self
+    }
+
+    pub fn status(mut self, s: Status) -> Self {
+        self.error.status = Some(s);
+        self
+    }
+
+    /// Set the kind of error, which determines the response status unless
+    /// one is set explicitly (see [`ErrorBuilder::into_response`]).
+    pub fn kind(mut self, k: Kind) -> Self {
+        self.kind = Some(k);
+        self
+    }
+
+    /// Describe the given error, i.e. take message and kind from it.
+    pub fn cause(self, e: &crate::Error) -> Self {
+        self.message(format!("{e}")).kind(e.code().kind)
+    }
+
+    pub fn error(&self) -> &Error<'a> {
+        &self.error
+    }
+
+    pub fn into_error(self) -> Error<'a> {
+        self.error
+    }
+
+    /// Wrap the error into a response to the request with the given id.
+    ///
+    /// The response status is the one set with [`ErrorBuilder::status`].
+    /// Otherwise it is inferred from the error [`Kind`], if any, and
+    /// defaults to [`Status::InternalServerError`]. The message is not
+    /// taken into account.
+    pub fn into_response(self, re: Id) -> ResponseBuilder<Error<'a>> {
+        let status = match (self.error.status, self.kind) {
+            (Some(s), _) => s,
+            (None, Some(k)) => Status::from(k),
+            (None, None) => Status::InternalServerError,
+        };
+        Response::builder(re, status).body(self.error)
+    }
+
+    pub fn encode<W>(&self, buf: W) -> Result<(), encode::Error<W::Error>>
+    where
+        W: Write,
+    {
+        Encoder::new(buf).encode(&self.error)?;
+        Ok(())
+    }
+
     pub fn to_vec(self) -> Result<Vec<u8>, encode::Error<<Vec<u8> as Write>::Error>> {
         let mut buf = Vec::new();
         self.encode(&mut buf)?;
@@ -560,6 +1890,49 @@ pub fn assert_response_match<'a>(struct_name: impl Into<Option<&'a str>>, cbor:
     }
 }
 
+/// Decode a request header from the front of the input.
+///
+/// Returns the header and the remaining bytes which contain the request
+/// body if the header indicates one.
+pub fn parse_request(bytes: &[u8]) -> Result<(Request<'_>, &[u8]), decode::Error> {
+    #[cfg(feature = "api-tracing")]
+    let span = tracing::debug_span!(
+        "ockam_api::parse_request",
+        id = tracing::field::Empty,
+        path = tracing::field::Empty
+    );
+    #[cfg(feature = "api-tracing")]
+    let _guard = span.enter();
+    let (req, rest) = parse::<Request>(bytes)?;
+    #[cfg(feature = "api-tracing")]
+    {
+        span.record("id", tracing::field::display(req.id()));
+        span.record("path", tracing::field::display(req.path()));
+    }
+    Ok((req, rest))
+}
+
+/// Decode a response header from the front of the input.
+///
+/// Returns the header and the remaining bytes which contain the response
+/// body if the header indicates one.
+pub fn parse_response(bytes: &[u8]) -> Result<(Response, &[u8]), decode::Error> {
+    parse(bytes)
+}
+
+/// Decode an error from the front of the input.
+///
+/// Returns the error and the remaining bytes.
+pub fn parse_error(bytes: &[u8]) -> Result<(Error<'_>, &[u8]), decode::Error> {
+    parse(bytes)
+}
+
+fn parse<'b, T: Decode<'b, ()>>(bytes: &'b [u8]) -> Result<(T, &'b [u8]), decode::Error> {
+    let mut dec = Decoder::new(bytes);
+    let t = dec.decode()?;
+    Ok((t, &bytes[dec.position()..]))
+}
+
 /// Decode response header only, without processing the message body.
 pub fn is_ok(label: &str, buf: &[u8]) -> Result<()> {
     let mut d = Decoder::new(buf);
@@ -648,3 +2021,869 @@ impl<C> Encode<C> for Cbor<'_> {
             .map_err(encode::Error::write)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn head_request_roundtrip() {
+        let req = Request::head("/node").to_vec().unwrap();
+        let hdr: Request = decode(&req).unwrap();
+        assert!(matches!(hdr.method(), Some(Method::Head)));
+        assert_eq!("/node", hdr.path());
+        assert!(!hdr.has_body())
+    }
+
+    #[test]
+    fn head_request_with_body() {
+        let req = Request::head("/node").body("x").to_vec().unwrap();
+        let (hdr, _) = parse_request(&req).unwrap();
+        assert!(hdr.has_body())
+    }
+
+    #[test]
+    fn head_response_without_body() {
+        let res = Response::ok(Id::fresh())
+            .method(Method::Head)
+            .to_vec()
+            .unwrap();
+        let hdr: Response = decode(&res).unwrap();
+        assert!(!hdr.has_body())
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn head_response_with_body() {
+        let _ = Response::ok(Id::fresh()).method(Method::Head).body("body");
+    }
+
+    #[test]
+    fn query_params() {
+        let req = Request::get("/nodes").query("verbose=true&&limit=50&all");
+        let req = req.to_vec().unwrap();
+        let hdr: Request = decode(&req).unwrap();
+        assert_eq!(Some("verbose=true&&limit=50&all"), hdr.query());
+        let params: Vec<_> = hdr.query_params().collect();
+        assert_eq!(
+            vec![("verbose", "true"), ("limit", "50"), ("all", "")],
+            params
+        );
+        assert_eq!(0, Request::get("/nodes").header().query_params().count())
+    }
+
+    #[test]
+    fn segments() {
+        let s = Segments::<4>::parse("");
+        assert_eq!(&[""], s.as_slice());
+        assert_eq!(Some(""), s.head());
+        assert!(s.tail().is_empty());
+
+        for path in ["node", "/node"] {
+            let s = Segments::<4>::parse(path);
+            assert_eq!(1, s.len());
+            assert_eq!(Some("node"), s.head());
+            assert_eq!(None, s.get(1));
+            assert!(s.tail().is_empty())
+        }
+
+        for path in ["node/tcp/listener", "/node/tcp/listener"] {
+            let s = Segments::<4>::parse(path);
+            assert_eq!(3, s.len());
+            assert_eq!(Some("node"), s.head());
+            assert_eq!(Some("listener"), s.get(2));
+            assert_eq!(&["tcp", "listener"], s.tail().as_slice());
+            assert_eq!(
+                vec!["node", "tcp", "listener"],
+                (&s).into_iter().collect::<Vec<_>>()
+            );
+            let mut n = 0;
+            for _ in s {
+                n += 1
+            }
+            assert_eq!(3, n)
+        }
+
+        let s = Segments::<2>::parse("/node/tcp/listener");
+        assert_eq!(&["node", "tcp/listener"], s.as_slice())
+    }
+
+    #[test]
+    fn segments_iter() {
+        for path in ["", "/", "node", "//node/create", "/node/tcp/listener/a/b"] {
+            let s = Segments::<4>::parse(path);
+            assert_eq!(s.as_slice(), s.iter().collect::<Vec<_>>());
+            assert_eq!(s.as_slice(), SegmentsIter::new(path, 4).collect::<Vec<_>>())
+        }
+
+        let req = Request::get("/nodes/create");
+        let mut i = req.header().path_segments_iter::<5>();
+        assert_eq!(Some("nodes"), i.next());
+        assert_eq!(Some("create"), i.next());
+        assert_eq!(None, i.next());
+
+        let mut i = SegmentsIter::new("/node/tcp/listener", 2);
+        assert_eq!(Some("node"), i.next());
+        assert_eq!(Some("tcp/listener"), i.next());
+        assert_eq!(None, i.next())
+    }
+
+    #[test]
+    fn segments_iter_borrows() {
+        let path = String::from("/node/tcp/listener");
+        let s = Segments::<4>::parse(&path);
+        let i = s.iter();
+        assert_eq!((3, Some(3)), i.size_hint());
+        for (a, b) in i.zip(s.as_slice()) {
+            assert!(core::ptr::eq(a, *b));
+            assert!(path.as_bytes().as_ptr_range().contains(&a.as_ptr()))
+        }
+    }
+
+    #[test]
+    fn segments_prefix() {
+        let s = Segments::<4>::parse("/node/tcp/listener");
+        assert!(s.starts_with(&[]));
+        assert!(s.starts_with(&["node", "tcp"]));
+        assert!(!s.starts_with(&["node", "udp"]));
+        assert_eq!(
+            &["listener"],
+            s.strip_prefix(&["node", "tcp"]).unwrap().as_slice()
+        );
+        assert!(s
+            .strip_prefix(&["node", "tcp", "listener"])
+            .unwrap()
+            .is_empty());
+        assert!(s.strip_prefix(&["cloud"]).is_none());
+
+        let long = ["node", "tcp", "listener", "x"];
+        assert!(!s.starts_with(&long));
+        assert!(s.strip_prefix(&long).is_none())
+    }
+
+    #[test]
+    fn segments_join() {
+        let s = Segments::<4>::parse("/a/b/c");
+        assert_eq!("/a/b/c", s.join());
+        assert_eq!("/b/c", s.strip_prefix(&["a"]).unwrap().join());
+        assert_eq!("/", s.strip_prefix(&["a", "b", "c"]).unwrap().join());
+        assert_eq!("/", Segments::<4>::parse("").join());
+        assert_eq!("/a/b", Segments::<4>::parse("a//b").join())
+    }
+
+    #[test]
+    fn display_error() {
+        let e = Error::new("/node");
+        assert_eq!("/node", e.to_string());
+        let e = e.with_method(Method::Post).with_message("no such node");
+        assert_eq!("[POST] /node: no such node", e.to_string());
+        let e: crate::Error = e.into();
+        assert!(e.to_string().contains("no such node"))
+    }
+
+    #[test]
+    fn error_detail_roundtrip() {
+        let e = Error::new("/nodes")
+            .with_message("bad request")
+            .with_detail("name too long");
+        let bytes = minicbor::to_vec(&e).unwrap();
+        let e: Error = decode(&bytes).unwrap();
+        assert_eq!(Some("bad request"), e.message());
+        assert_eq!(Some("name too long"), e.detail());
+        assert!(Error::new("/nodes").detail().is_none())
+    }
+
+    #[test]
+    fn response_from_error() {
+        let e = Error::new("/nodes/n1")
+            .with_message("no such node")
+            .with_status(Status::NotFound);
+        let res = Response::from_error(Id::ZERO, &e);
+        assert_eq!(Some(Status::NotFound), res.header().status());
+        let bytes = res.to_vec().unwrap();
+        let (hdr, body) = parse_response(&bytes).unwrap();
+        assert!(hdr.has_body());
+        let e: Error = decode(body).unwrap();
+        assert_eq!(Some("no such node"), e.message());
+        assert_eq!(Status::NotFound, e.inferred_status());
+
+        let e = Error::new("/nodes/n1");
+        assert_eq!(Status::InternalServerError, e.inferred_status());
+        let res = Response::from_error(Id::ZERO, &e);
+        assert_eq!(Some(Status::InternalServerError), res.header().status())
+    }
+
+    #[test]
+    fn error_builder_roundtrip() {
+        let bytes = Error::build("/node")
+            .method(Method::Delete)
+            .message("node not found")
+            .to_vec()
+            .unwrap();
+        let e: Error = decode(&bytes).unwrap();
+        assert_eq!(Some("/node"), e.path());
+        assert!(matches!(e.method(), Some(Method::Delete)));
+        assert_eq!(Some("node not found"), e.message());
+
+        let r = Error::build("/node")
+            .message("boom")
+            .status(Status::NotFound);
+        assert_eq!(
+            Some(Status::NotFound),
+            r.into_response(Id::fresh()).header().status()
+        );
+        // The message does not affect the status:
+        let r = Error::build("/node").message("node not found");
+        assert_eq!(
+            Some(Status::InternalServerError),
+            r.into_response(Id::fresh()).header().status()
+        );
+
+        // The kind does, unless the status is set explicitly:
+        let e = crate::Error::new(Origin::Node, Kind::NotFound, "no such node");
+        let r = Error::build("/node").cause(&e).into_response(Id::fresh());
+        assert_eq!(Some(Status::NotFound), r.header().status());
+        assert_eq!(Some("no such node"), r.into_parts().1.unwrap().message());
+        let r = Error::build("/node")
+            .kind(Kind::Invalid)
+            .status(Status::UnprocessableEntity);
+        assert_eq!(
+            Some(Status::UnprocessableEntity),
+            r.into_response(Id::fresh()).header().status()
+        );
+        let r = Error::build("/node").kind(Kind::Io);
+        assert_eq!(
+            Some(Status::InternalServerError),
+            r.into_response(Id::fresh()).header().status()
+        )
+    }
+
+    #[test]
+    fn into_owned() {
+        let req = {
+            let buf = Request::get("/node").query("all").to_vec().unwrap();
+            decode::<Request>(&buf).unwrap().into_owned()
+        };
+        assert_eq!("/node", req.path());
+        assert_eq!(Some("all"), req.query());
+
+        let err = {
+            let buf = Error::build("/node").message("boom").to_vec().unwrap();
+            decode::<Error>(&buf).unwrap().into_owned()
+        };
+        assert_eq!(Some("/node"), err.path());
+        assert_eq!(Some("boom"), err.message())
+    }
+
+    #[test]
+    fn encode_to_vec() {
+        let req = Request::post("/node").body("body");
+        let mut buf = Vec::new();
+        req.encode(&mut buf).unwrap();
+        assert_eq!(buf, req.encode_to_vec().unwrap());
+
+        let res = Response::ok(Id::fresh()).body("body");
+        let mut buf = Vec::new();
+        res.encode(&mut buf).unwrap();
+        assert_eq!(buf, res.encode_to_vec().unwrap())
+    }
+
+    #[test]
+    fn parse_header_and_tail() {
+        let bytes = Request::get("/node").to_vec().unwrap();
+        let (req, tail) = parse_request(&bytes).unwrap();
+        assert!(!req.has_body());
+        assert!(tail.is_empty());
+
+        let bytes = Response::ok(Id::fresh()).body("body").to_vec().unwrap();
+        let (res, tail) = parse_response(&bytes).unwrap();
+        assert!(res.has_body());
+        assert_eq!("body", decode::<&str>(tail).unwrap());
+
+        let bytes = Error::build("/node").message("boom").to_vec().unwrap();
+        let (err, tail) = parse_error(&bytes).unwrap();
+        assert_eq!(Some("boom"), err.message());
+        assert!(tail.is_empty());
+
+        assert!(parse_request(&bytes[..0]).is_err())
+    }
+
+    #[test]
+    fn parse_id() {
+        for _ in 0..100 {
+            let id = Id::fresh();
+            assert_eq!(Ok(id), id.to_string().parse());
+            assert_eq!(Ok(id), id.to_string().to_uppercase().parse())
+        }
+        assert_eq!(Ok(Id(0xab)), Id::from_hex("000000aB"));
+        assert_eq!(Err(IdParseError::InvalidLength(2)), Id::from_hex("ab"));
+        assert_eq!(Err(IdParseError::InvalidDigit), Id::from_hex("+0000001"));
+        assert_eq!(Err(IdParseError::InvalidDigit), Id::from_hex("0000000g"));
+        assert!("not an id".parse::<Id>().is_err())
+    }
+
+    #[test]
+    fn id_formats() {
+        let id = Id(0x00ab_12cd);
+        assert_eq!("00ab12cd", id.to_string());
+        assert_eq!(format!("{id:x}"), id.to_string());
+        assert_eq!("00AB12CD", format!("{id:X}"));
+        assert_eq!("00000000101010110001001011001101", format!("{id:b}"));
+        assert_eq!(format!("{id:08x}"), format!("{id:08X}").to_lowercase());
+        assert_ne!(format!("{id:08x}"), format!("{id:08X}"));
+        for _ in 0..100 {
+            let id = Id::fresh();
+            assert_eq!(format!("{id:08x}"), format!("{id:08X}").to_lowercase());
+            assert_eq!(Ok(id.0), u32::from_str_radix(&format!("{id:b}"), 2))
+        }
+    }
+
+    #[test]
+    fn zero_id() {
+        assert!(Id::ZERO.is_zero());
+        assert!(Id::default().is_zero());
+        assert!((0..1000).all(|_| !Id::fresh().is_zero()))
+    }
+
+    #[test]
+    fn method_equality() {
+        assert_eq!(Method::Get, Method::Get);
+        assert_ne!(Method::Get, Method::Post);
+        assert_eq!(Some(Method::Put), Some(Method::Put));
+        assert_ne!(Some(Method::Put), None);
+        let cbor = minicbor::to_vec(Method::Options).unwrap();
+        assert_eq!(Method::Options, decode::<Method>(&cbor).unwrap())
+    }
+
+    #[test]
+    fn method_as_map_key() {
+        use crate::compat::collections::HashMap;
+
+        let mut m = HashMap::new();
+        m.insert(Method::Get, "get");
+        m.insert(Method::Post, "post");
+        assert_eq!(Some(&"get"), m.get(&Method::Get));
+        assert_eq!(Some(&"post"), m.get(&Method::Post));
+        assert_eq!(None, m.get(&Method::Put))
+    }
+
+    #[test]
+    fn status_classes() {
+        let all = (0..=u16::MAX).filter_map(|n| Status::try_from(n).ok());
+        for s in all {
+            let n = u16::from(s);
+            assert_eq!(n / 100 == 2, s.is_success(), "{s}");
+            assert_eq!(n / 100 == 4, s.is_client_error(), "{s}");
+            assert_eq!(n / 100 == 5, s.is_server_error(), "{s}");
+            assert!(!s.reason_phrase().is_empty())
+        }
+        assert_eq!("OK", Status::Ok.reason_phrase());
+        assert_eq!(
+            "Internal Server Error",
+            Status::InternalServerError.reason_phrase()
+        )
+    }
+
+    #[test]
+    fn method_properties() {
+        assert!(Method::Get.is_safe() && Method::Head.is_safe());
+        assert!(!Method::Post.is_safe() && !Method::Put.is_safe());
+        assert!(!Method::Delete.is_safe() && !Method::Patch.is_safe());
+        assert!(Method::Get.is_idempotent() && Method::Head.is_idempotent());
+        assert!(Method::Put.is_idempotent() && Method::Delete.is_idempotent());
+        assert!(!Method::Post.is_idempotent() && !Method::Patch.is_idempotent());
+        assert!(!Method::Options.is_safe() && !Method::Options.is_idempotent())
+    }
+
+    #[test]
+    fn success_status_roundtrip() {
+        for s in [Status::Created, Status::Accepted, Status::NoContent] {
+            let cbor = minicbor::to_vec(s).unwrap();
+            assert_eq!(s, decode::<Status>(&cbor).unwrap());
+            assert!(s.is_success())
+        }
+        let res = Response::no_content(Id::fresh()).to_vec().unwrap();
+        let (res, _) = parse_response(&res).unwrap();
+        assert_eq!(Some(Status::NoContent), res.status());
+        assert!(!res.has_body())
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn no_content_with_body() {
+        let _ = Response::no_content(Id::fresh()).body("body");
+    }
+
+    #[test]
+    fn retry_after() {
+        let res = Response::service_unavailable_retry_after(Id::fresh(), 30);
+        let bytes = res.to_vec().unwrap();
+        let (res, tail) = parse_response(&bytes).unwrap();
+        assert_eq!(Some(Status::ServiceUnavailable), res.status());
+        assert_eq!(30u32, res.decode_body::<u32>(tail).unwrap());
+        assert!(Status::GatewayTimeout.is_server_error())
+    }
+
+    #[cfg(feature = "api-serde")]
+    #[test]
+    fn serde_json_roundtrip() {
+        let req = Request::put("/node").query("all").into_parts().0;
+        let json = serde_json::to_string(&req).unwrap();
+        assert!(json.contains(r#""method":"PUT""#));
+        assert!(json.contains(&format!(r#""id":"{}""#, req.id())));
+        let r: Request = serde_json::from_str(&json).unwrap();
+        assert_eq!(req.id(), r.id());
+        assert_eq!(Some(Method::Put), r.method());
+        assert_eq!("/node", r.path());
+        assert_eq!(Some("all"), r.query());
+
+        let res = Response::new(req.id(), Status::NotFound, false);
+        let json = serde_json::to_string(&res).unwrap();
+        assert!(json.contains(r#""status":404"#));
+        let r: Response = serde_json::from_str(&json).unwrap();
+        assert_eq!(res.id(), r.id());
+        assert_eq!(req.id(), r.re());
+        assert_eq!(Some(Status::NotFound), r.status());
+
+        assert!(serde_json::from_str::<Method>(r#""CONNECT""#).is_err());
+        assert!(serde_json::from_str::<Status>("999").is_err());
+        assert!(serde_json::from_str::<Id>(r#""xyz""#).is_err());
+
+        let err = Error::new("/node")
+            .with_method(Method::Delete)
+            .with_message("boom")
+            .with_status(Status::Conflict);
+        let json = serde_json::to_string(&err).unwrap();
+        assert!(json.contains(r#""status":409"#));
+        let e: Error = serde_json::from_str(&json).unwrap();
+        assert_eq!(Some("/node"), e.path());
+        assert_eq!(Some(Method::Delete), e.method());
+        assert_eq!(Some("boom"), e.message());
+        assert_eq!(Some(Status::Conflict), e.status());
+        assert!(e.detail().is_none())
+    }
+
+    #[test]
+    fn bearer_auth() {
+        let bytes = Request::get("/node").bearer_auth("token").to_vec().unwrap();
+        let (req, _) = parse_request(&bytes).unwrap();
+        assert_eq!(Some("token"), req.auth_token());
+        assert_eq!(Some("token"), req.into_owned().auth_token());
+        let bytes = Request::get("/node").to_vec().unwrap();
+        assert_eq!(None, parse_request(&bytes).unwrap().0.auth_token())
+    }
+
+    #[test]
+    fn context() {
+        #[derive(Debug, PartialEq, Encode, Decode)]
+        #[cbor(map)]
+        struct TraceCtx<'a> {
+            #[b(1)]
+            tenant: &'a str,
+            #[n(2)]
+            hops: Vec<u32>,
+        }
+
+        let ctx = TraceCtx {
+            tenant: "t1",
+            hops: vec![1, 2, 3],
+        };
+        let expected = minicbor::to_vec(&ctx).unwrap();
+        let bytes = Request::post("/node")
+            .with_context(&ctx)
+            .unwrap()
+            .body("hello")
+            .to_vec()
+            .unwrap();
+        let (req, body) = parse_request(&bytes).unwrap();
+        assert_eq!(Some(expected.as_slice()), req.context_bytes());
+        assert_eq!(ctx, decode(req.context_bytes().unwrap()).unwrap());
+        assert_eq!("hello", req.decode_body::<&str>(body).unwrap());
+        assert_eq!(Some(expected.as_slice()), req.into_owned().context_bytes());
+
+        let bytes = Request::get("/node").to_vec().unwrap();
+        assert_eq!(None, parse_request(&bytes).unwrap().0.context_bytes())
+    }
+
+    #[test]
+    fn deadline() {
+        use core::time::Duration;
+
+        let bytes = Request::get("/node")
+            .timeout(Duration::from_secs(60))
+            .to_vec()
+            .unwrap();
+        let (req, _) = parse_request(&bytes).unwrap();
+        let d = req.deadline().unwrap();
+        assert!(!req.is_expired());
+        assert!(!req.is_expired_at(d));
+        assert!(req.is_expired_at(d + 1));
+
+        let past = std::time::SystemTime::now() - Duration::from_secs(1);
+        let req = Request::get("/node").deadline(past);
+        assert!(req.header().is_expired());
+        assert!(!Request::get("/node").header().is_expired())
+    }
+
+    #[test]
+    fn idempotency_key() {
+        let bytes = Request::post("/node")
+            .idempotency_key("k1")
+            .to_vec()
+            .unwrap();
+        let (req, _) = parse_request(&bytes).unwrap();
+        assert_eq!(Some("k1"), req.idempotency_key());
+        assert_eq!(Some("k1"), req.into_owned().idempotency_key())
+    }
+
+    #[test]
+    fn response_classes() {
+        let all = (0..=u16::MAX).filter_map(|n| Status::try_from(n).ok());
+        for s in all {
+            let r = Response::new(Id::fresh(), s, false);
+            assert_eq!(s.is_success(), r.is_success());
+            assert_eq!(s.is_client_error(), r.is_client_error());
+            assert_eq!(s.is_server_error(), r.is_server_error());
+            assert_eq!(!s.is_success(), r.is_error())
+        }
+        let mut r = Response::new(Id::fresh(), Status::Ok, false);
+        r.status = None;
+        assert!(!r.is_success());
+        assert!(!r.is_error())
+    }
+
+    #[test]
+    fn into_result() {
+        let bytes = Response::ok(Id::fresh()).body(42u32).to_vec().unwrap();
+        let (res, body) = parse_response(&bytes).unwrap();
+        assert_eq!(Ok(42u32), res.into_result(body).map_err(|e| e.to_string()));
+
+        let err = Error::new("/node").with_message("no such node");
+        let bytes = Response::not_found(Id::fresh()).body(err).to_vec().unwrap();
+        let (res, body) = parse_response(&bytes).unwrap();
+        let e = res.into_result::<u32>(body).unwrap_err();
+        assert_eq!(Some("no such node"), e.message());
+
+        let bytes = Response::bad_request(Id::fresh())
+            .body("??")
+            .to_vec()
+            .unwrap();
+        let (res, body) = parse_response(&bytes).unwrap();
+        let e = res.into_result::<u32>(body).unwrap_err();
+        assert_eq!(Some("400 Bad Request"), e.message());
+
+        let bytes = Response::ok(Id::fresh()).body("??").to_vec().unwrap();
+        let (res, body) = parse_response(&bytes).unwrap();
+        assert!(res.into_result::<u32>(body).is_err())
+    }
+
+    #[test]
+    fn display_method_and_status() {
+        assert_eq!("HEAD", Method::Head.to_string());
+        assert_eq!("200 OK", Status::Ok.to_string());
+        assert_eq!("404 Not Found", Status::NotFound.to_string());
+    }
+
+    #[test]
+    fn parse_method_and_status() {
+        assert!(matches!("get".parse::<Method>(), Ok(Method::Get)));
+        assert!(matches!("OPTIONS".parse::<Method>(), Ok(Method::Options)));
+        assert!("CONNECT".parse::<Method>().is_err());
+        assert_eq!(Ok(Status::NotFound), "404".parse());
+        assert_eq!(Ok(Status::Ok), Status::try_from(200));
+        assert_eq!(Ok(Status::BadRequest), Status::try_from(400));
+        assert_eq!(Err(UnknownStatus(999)), Status::try_from(999));
+        assert_eq!(Err(UnknownStatus(0)), Status::try_from(0));
+        assert_eq!(Ok(Status::Conflict), Status::Conflict.to_string().parse());
+        assert!("999".parse::<Status>().is_err());
+        assert_eq!(Ok(Status::Ok), "OK".parse());
+        assert_eq!(Ok(Status::NotFound), "not found".parse());
+        assert_eq!(Ok(Status::TooManyRequests), "Too Many Requests".parse());
+        assert_eq!(Ok(Status::Locked), " LOCKED ".parse());
+        assert!("not-found".parse::<Status>().is_err());
+        assert!("I'm a teapot".parse::<Status>().is_err());
+        assert!("".parse::<Status>().is_err());
+        for status in (100..600).filter_map(|n| Status::try_from(n).ok()) {
+            let phrase = status.reason_phrase().to_lowercase();
+            assert_eq!(Ok(status), phrase.parse())
+        }
+    }
+
+    #[test]
+    fn method_try_from_str() {
+        assert_eq!(Ok(Method::Get), Method::try_from("GET"));
+        assert_eq!(Ok(Method::Get), Method::try_from("get"));
+        assert_eq!(Ok(Method::Patch), Method::try_from(String::from("Patch")));
+        let e = Method::try_from("UNKNOWN").unwrap_err();
+        assert_eq!(UnknownMethodName("UNKNOWN".into()), e);
+        assert_eq!("unknown request method: UNKNOWN", e.to_string());
+        assert!(Method::try_from(String::new()).is_err())
+    }
+
+    #[test]
+    fn path_append() {
+        for (base, segment) in [
+            ("/nodes/", "n1"),
+            ("/nodes", "/n1"),
+            ("/nodes/", "/n1"),
+            ("/nodes", "n1"),
+        ] {
+            let req = Request::get(base).path_append(segment);
+            assert_eq!("/nodes/n1", req.header().path())
+        }
+    }
+
+    #[test]
+    fn path_prefix() {
+        for (prefix, path) in [
+            ("/v0/", "nodes"),
+            ("/v0", "/nodes"),
+            ("/v0/", "/nodes"),
+            ("/v0", "nodes"),
+        ] {
+            let req = Request::get(path).path_prefix(prefix);
+            assert_eq!("/v0/nodes", req.header().path())
+        }
+    }
+
+    #[test]
+    fn default_builders() {
+        let req = RequestBuilder::default();
+        assert_eq!(Some(Method::Get), req.header().method());
+        assert_eq!("/", req.header().path());
+        assert!(!req.header().has_body());
+
+        let res = ResponseBuilder::default();
+        assert_eq!(Some(Status::Ok), res.header().status());
+        assert_eq!(Id::ZERO, res.header().re());
+        assert!(!res.header().has_body())
+    }
+
+    #[test]
+    fn inspect_builders() {
+        let mut seen = None;
+        let req = Request::get("/nodes").inspect(|h| seen = Some(h.id()));
+        assert_eq!(Some(req.header().id()), seen);
+
+        let mut seen = None;
+        let res = Response::ok(Id::ZERO).inspect(|h| seen = Some(h.id()));
+        assert_eq!(Some(res.header().id()), seen)
+    }
+
+    #[test]
+    fn correlation() {
+        let req = Request::get("/nodes").into_parts().0;
+        let res = Response::new(req.id(), Status::Ok, false);
+        assert!(res.correlates_to(&req));
+        assert!(req.expects_response_id(res.re()));
+
+        let other = Response::new(Id::fresh(), Status::Ok, false);
+        assert!(!other.correlates_to(&req));
+        assert!(!req.expects_response_id(other.re()))
+    }
+
+    #[test]
+    fn request_into_method_and_segments() {
+        let req = Request::get("/node/tcp/listener").into_parts().0;
+        let (method, segments) = <(Method, Vec<String>)>::try_from(req).unwrap();
+        assert_eq!(Method::Get, method);
+        assert_eq!(vec!["node", "tcp", "listener"], segments);
+
+        let mut req = Request::post("/node").into_parts().0;
+        req.method = None;
+        let e = <(Method, Vec<String>)>::try_from(req).unwrap_err();
+        assert_eq!(Some("/node"), e.path());
+        assert_eq!(Some("unknown method"), e.message());
+        assert_eq!(Some(Status::BadRequest), e.status())
+    }
+
+    #[test]
+    fn request_builder_without_method() {
+        let b = RequestBuilder::new("/nodes").query("limit=1");
+        assert_eq!(None, b.header().method());
+        let bytes = b.method(Method::Post).body("n1").to_vec().unwrap();
+        let (req, body) = parse_request(&bytes).unwrap();
+        assert_eq!(Some(Method::Post), req.method());
+        assert_eq!(Some("limit=1"), req.query());
+        assert_eq!("n1", req.decode_body::<&str>(body).unwrap())
+    }
+
+    #[test]
+    fn request_builder_merge() {
+        let base = || {
+            Request::get("/base")
+                .id(Id(1))
+                .query("limit=10")
+                .bearer_auth("t0k3n")
+        };
+
+        // Nothing is set in `other`:
+        let b = base().merge(&RequestBuilder::default());
+        assert_eq!(base().to_vec().unwrap(), b.to_vec().unwrap());
+        let b = Request::post("/base").merge(&RequestBuilder::default());
+        assert_eq!(Some(Method::Post), b.header().method());
+        assert_eq!("/base", b.header().path());
+
+        // Only the path is set in `other`:
+        let m = base().merge(&RequestBuilder::new("/other"));
+        assert_eq!("/other", m.header().path());
+        assert_eq!(Id(1), m.header().id());
+        assert_eq!(Some(Method::Get), m.header().method());
+        assert_eq!(Some("limit=10"), m.header().query());
+
+        let m = base().merge(&RequestBuilder::default().id(Id(2)));
+        assert_eq!("/base", m.header().path());
+        assert_eq!(Id(2), m.header().id());
+
+        let m = base().merge(&Request::put("/").query("limit=1").idempotency_key("k"));
+        assert_eq!(Some(Method::Put), m.header().method());
+        assert_eq!(Some("limit=1"), m.header().query());
+        assert_eq!(Some("t0k3n"), m.header().auth_token());
+        assert_eq!(Some("k"), m.header().idempotency_key());
+        assert!(!m.header().has_body());
+
+        // Bodies:
+        let no_body = RequestBuilder::<&str, MethodUnset> {
+            header: RequestBuilder::new("/base").header,
+            body: None,
+            explicit: Explicit::default(),
+            state: PhantomData,
+        };
+        let m = base().body("base").merge(&no_body);
+        assert!(m.header().has_body());
+        assert_eq!(Some("base"), m.into_parts().1);
+
+        let m = base().body("base").merge(&Request::post("/").body("other"));
+        assert_eq!(Some(Method::Post), m.header().method());
+        assert!(m.header().has_body());
+        assert_eq!(Some("other"), m.into_parts().1)
+    }
+
+    #[test]
+    fn request_display() {
+        let req = Request::get("/nodes/foo").id(Id(0x0a1b2c3d));
+        assert_eq!("GET /nodes/foo (id=0a1b2c3d)", req.header().to_string());
+        let mut hdr = req.into_parts().0;
+        hdr.method = None;
+        assert_eq!("UNKNOWN /nodes/foo (id=0a1b2c3d)", hdr.to_string())
+    }
+
+    #[test]
+    fn response_display() {
+        let res = Response::ok(Id(0x0a1b2c3d)).id(Id(0x0e1f2031));
+        assert_eq!("200 OK re=0a1b2c3d (id=0e1f2031)", res.header().to_string());
+        let mut hdr = res.into_parts().0;
+        hdr.status = None;
+        assert_eq!("??? re=0a1b2c3d (id=0e1f2031)", hdr.to_string())
+    }
+
+    #[test]
+    fn method_numeric_roundtrip() {
+        for n in 0..=u8::MAX {
+            match Method::try_from(n) {
+                Ok(m) => assert_eq!(n, u8::from(m)),
+                Err(e) => assert_eq!(UnknownMethod(n), e),
+            }
+        }
+        assert_eq!(Err(UnknownMethod(7)), Method::try_from(7).map(u8::from))
+    }
+
+    #[test]
+    fn numeric_values() {
+        assert_eq!(200, Status::Ok.as_u16());
+        assert_eq!(404, Status::NotFound.as_u16());
+        assert_eq!(0, Method::Get.as_u8());
+        assert_eq!(3, Method::Delete.as_u8())
+    }
+
+    #[test]
+    fn numeric_comparisons() {
+        assert_eq!(Status::Ok, 200u16);
+        assert_eq!(404u16, Status::NotFound);
+        for n in 0..=u16::MAX {
+            match Status::try_from(n) {
+                Ok(s) => {
+                    assert_eq!(s, n);
+                    assert_eq!(n, s);
+                    assert_ne!(s, n.wrapping_add(1))
+                }
+                Err(_) => {
+                    assert_ne!(Status::Ok, n);
+                    assert_ne!(n, Status::Ok)
+                }
+            }
+        }
+        assert_eq!(Method::Post, 1u8);
+        for n in 0..=u8::MAX {
+            match Method::try_from(n) {
+                Ok(m) => {
+                    assert_eq!(m, n);
+                    assert_eq!(n, m);
+                    assert_ne!(m, n.wrapping_add(1))
+                }
+                Err(_) => {
+                    assert_ne!(Method::Get, n);
+                    assert_ne!(n, Method::Get)
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn status_order() {
+        assert!(Status::Ok < Status::BadRequest);
+        assert!(Status::BadRequest < Status::InternalServerError);
+        let all: Vec<Status> = (0..=u16::MAX)
+            .filter_map(|n| Status::try_from(n).ok())
+            .collect();
+        for a in &all {
+            for b in &all {
+                assert_eq!(a.cmp(b), u16::from(*a).cmp(&u16::from(*b)))
+            }
+        }
+    }
+
+    #[test]
+    fn status_numeric_roundtrip() {
+        for n in 0..=u16::MAX {
+            match Status::try_from(n) {
+                Ok(s) => {
+                    assert_eq!(n, u16::from(s));
+                    let cbor = minicbor::to_vec(s).unwrap();
+                    assert_eq!(n, decode::<u16>(&cbor).unwrap())
+                }
+                Err(e) => assert_eq!(UnknownStatus(n), e),
+            }
+        }
+    }
+
+    #[cfg(feature = "api-tracing")]
+    mod spans {
+        use super::*;
+        use crate::api::test_support::Recorder;
+
+        #[test]
+        fn encode_and_parse_emit_spans() {
+            let rec = Recorder::default();
+            let req = Request::post("/node/vault");
+            let id = req.header().id();
+            let bytes = rec.with_default(|| {
+                let bytes = req.to_vec().unwrap();
+                parse_request(&bytes).unwrap();
+                bytes
+            });
+            assert!(!bytes.is_empty());
+            let spans = rec.spans();
+            assert_eq!(2, spans.len());
+            assert_eq!("ockam_api::encode_request", spans[0].0);
+            assert!(spans[0].1.contains(&format!("id={id}")));
+            assert!(spans[0].1.contains("method=POST"));
+            assert!(spans[0].1.contains("path=/node/vault"));
+            assert_eq!("ockam_api::parse_request", spans[1].0);
+            assert!(spans[1].1.contains(&format!("id={id}")));
+            assert!(spans[1].1.contains("path=/node/vault"));
+        }
+    }
+}
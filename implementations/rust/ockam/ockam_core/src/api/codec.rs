@@ -0,0 +1,168 @@
+//! Pluggable wire formats for API messages.
+
+use crate::compat::vec::Vec;
+use core::fmt;
+use minicbor::encode::{self, Write};
+use minicbor::{decode, Decode, Encode};
+#[cfg(feature = "json")]
+use serde::{Deserialize, Serialize};
+
+/// A wire format for API headers and bodies.
+///
+/// Which values a codec supports is up to the codec, e.g. [`CborCodec`]
+/// handles all `minicbor` types, see [`CodecEncode`] and [`CodecDecode`].
+pub trait Codec: Send + Sync {}
+
+/// A [`Codec`] which can encode values of type `T`.
+pub trait CodecEncode<T: ?Sized>: Codec {
+    /// Encode a value into a new byte vector.
+    fn encode(&self, val: &T) -> Result<Vec<u8>, CodecError>;
+}
+
+/// A [`Codec`] which can decode values of type `T` from bytes of lifetime `'b`.
+pub trait CodecDecode<'b, T>: Codec {
+    /// Decode a value from the given bytes.
+    fn decode(&self, bytes: &'b [u8]) -> Result<T, CodecError>;
+}
+
+/// The default codec, using CBOR via `minicbor`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CborCodec;
+
+impl Codec for CborCodec {}
+
+impl<T: Encode<()> + ?Sized> CodecEncode<T> for CborCodec {
+    fn encode(&self, val: &T) -> Result<Vec<u8>, CodecError> {
+        Ok(minicbor::to_vec(val)?)
+    }
+}
+
+impl<'b, T: Decode<'b, ()>> CodecDecode<'b, T> for CborCodec {
+    fn decode(&self, bytes: &'b [u8]) -> Result<T, CodecError> {
+        Ok(decode(bytes)?)
+    }
+}
+
+/// A codec using JSON via `serde_json`.
+#[cfg(feature = "json")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct JsonCodec;
+
+#[cfg(feature = "json")]
+impl Codec for JsonCodec {}
+
+#[cfg(feature = "json")]
+impl<T: Serialize + ?Sized> CodecEncode<T> for JsonCodec {
+    fn encode(&self, val: &T) -> Result<Vec<u8>, CodecError> {
+        Ok(serde_json::to_vec(val)?)
+    }
+}
+
+#[cfg(feature = "json")]
+impl<'b, T: Deserialize<'b>> CodecDecode<'b, T> for JsonCodec {
+    fn decode(&self, bytes: &'b [u8]) -> Result<T, CodecError> {
+        Ok(serde_json::from_slice(bytes)?)
+    }
+}
+
+/// Errors produced by a [`Codec`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum CodecError {
+    /// CBOR encoding failed.
+    Encode(encode::Error<<Vec<u8> as Write>::Error>),
+    /// CBOR decoding failed.
+    Decode(decode::Error),
+    /// JSON encoding or decoding failed.
+    #[cfg(feature = "json")]
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for CodecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CodecError::Encode(e) => write!(f, "failed to encode CBOR: {e}"),
+            CodecError::Decode(e) => write!(f, "failed to decode CBOR: {e}"),
+            #[cfg(feature = "json")]
+            CodecError::Json(e) => write!(f, "JSON codec error: {e}"),
+        }
+    }
+}
+
+impl crate::compat::error::Error for CodecError {}
+
+impl From<encode::Error<<Vec<u8> as Write>::Error>> for CodecError {
+    fn from(e: encode::Error<<Vec<u8> as Write>::Error>) -> Self {
+        CodecError::Encode(e)
+    }
+}
+
+impl From<decode::Error> for CodecError {
+    fn from(e: decode::Error) -> Self {
+        CodecError::Decode(e)
+    }
+}
+
+#[cfg(feature = "json")]
+impl From<serde_json::Error> for CodecError {
+    fn from(e: serde_json::Error) -> Self {
+        CodecError::Json(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{Method, Request};
+
+    #[test]
+    fn cbor_roundtrips_all_requests() {
+        let methods = [
+            Method::Get,
+            Method::Post,
+            Method::Put,
+            Method::Delete,
+            Method::Patch,
+            Method::Head,
+            Method::Options,
+        ];
+        for m in methods {
+            let req = Request::builder(m, "/node/services")
+                .query("limit=10")
+                .bearer_auth("token")
+                .idempotency_key("key-1")
+                .into_parts()
+                .0;
+            let bytes = CborCodec.encode(&req).unwrap();
+            let dec: Request = CborCodec.decode(&bytes).unwrap();
+            assert_eq!(req.id(), dec.id());
+            assert_eq!(Some(m), dec.method());
+            assert_eq!("/node/services", dec.path());
+            assert_eq!(Some("limit=10"), dec.query());
+            assert_eq!(Some("token"), dec.auth_token());
+            assert_eq!(Some("key-1"), dec.idempotency_key());
+        }
+    }
+
+    #[test]
+    fn encode_with_matches_encode() {
+        let builder = Request::post("/node/vault").body("hello");
+        let mut buf = Vec::new();
+        builder.encode_with(&CborCodec, &mut buf).unwrap();
+        assert_eq!(builder.encode_to_vec().unwrap(), buf);
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json_roundtrip() {
+        let req = Request::get("/node/services")
+            .query("limit=10")
+            .into_parts()
+            .0;
+        let bytes = JsonCodec.encode(&req).unwrap();
+        let dec: Request = JsonCodec.decode(&bytes).unwrap();
+        assert_eq!(req.id(), dec.id());
+        assert_eq!("/node/services", dec.path());
+        assert_eq!(Some("limit=10"), dec.query());
+    }
+}
@@ -0,0 +1,195 @@
+//! Conversions between API messages and `http` types for HTTP gateways.
+
+use super::{
+    Id, Method, Request, RequestBuilder, Response, ResponseBuilder, Status, UnknownStatus,
+};
+use crate::compat::string::ToString;
+use crate::compat::vec::Vec;
+use bytes::Bytes;
+use core::fmt;
+use minicbor::encode::{self, Write};
+use minicbor::Encode;
+
+/// HTTP header carrying the request ID, in hex.
+///
+/// Responses may echo it back so they can be correlated with their request.
+pub const REQUEST_ID_HEADER: &str = "ockam-request-id";
+
+impl From<Method> for http::Method {
+    fn from(m: Method) -> Self {
+        match m {
+            Method::Get => http::Method::GET,
+            Method::Post => http::Method::POST,
+            Method::Put => http::Method::PUT,
+            Method::Delete => http::Method::DELETE,
+            Method::Patch => http::Method::PATCH,
+            Method::Head => http::Method::HEAD,
+            Method::Options => http::Method::OPTIONS,
+        }
+    }
+}
+
+impl TryFrom<Request<'_>> for http::Request<Bytes> {
+    type Error = HttpError;
+
+    fn try_from(r: Request<'_>) -> Result<Self, Self::Error> {
+        to_http_request(&r, Bytes::new())
+    }
+}
+
+impl<T: Encode<()>> TryFrom<RequestBuilder<'_, T>> for http::Request<Bytes> {
+    type Error = HttpError;
+
+    /// Convert the request, encoding its body (if any) as CBOR.
+    fn try_from(b: RequestBuilder<'_, T>) -> Result<Self, Self::Error> {
+        let (header, body) = b.into_parts();
+        let body = match body {
+            Some(b) => Bytes::from(minicbor::to_vec(&b)?),
+            None => Bytes::new(),
+        };
+        to_http_request(&header, body)
+    }
+}
+
+/// Convert an HTTP response into a response header and the raw body.
+///
+/// The ID of the corresponding request is taken from the
+/// [`REQUEST_ID_HEADER`] if present, otherwise it is [`Id::ZERO`].
+impl TryFrom<http::Response<Bytes>> for ResponseBuilder<Bytes> {
+    type Error = HttpError;
+
+    fn try_from(r: http::Response<Bytes>) -> Result<Self, Self::Error> {
+        let status = Status::try_from(r.status().as_u16())?;
+        let re = r
+            .headers()
+            .get(REQUEST_ID_HEADER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(Id::ZERO);
+        let body = r.into_body();
+        let has_body = !body.is_empty();
+        Ok(ResponseBuilder {
+            header: Response::new(re, status, has_body),
+            body: if has_body { Some(body) } else { None },
+            method: None,
+        })
+    }
+}
+
+fn to_http_request(r: &Request<'_>, body: Bytes) -> Result<http::Request<Bytes>, HttpError> {
+    let method = r.method().ok_or(HttpError::MissingMethod)?;
+    let uri = match r.query() {
+        Some(q) => format!("{}?{q}", r.path()),
+        None => r.path().to_string(),
+    };
+    let mut b = http::Request::builder()
+        .method(http::Method::from(method))
+        .uri(uri)
+        .header(REQUEST_ID_HEADER, r.id().to_string());
+    if let Some(t) = r.auth_token() {
+        b = b.header(http::header::AUTHORIZATION, format!("Bearer {t}"))
+    }
+    Ok(b.body(body)?)
+}
+
+/// Errors converting between API messages and `http` types.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum HttpError {
+    /// The HTTP message could not be constructed, e.g. because of an invalid URI.
+    Http(http::Error),
+    /// The request body could not be encoded.
+    Encode(encode::Error<<Vec<u8> as Write>::Error>),
+    /// The HTTP status code has no API equivalent.
+    UnknownStatus(UnknownStatus),
+    /// The request has no (known) method.
+    MissingMethod,
+}
+
+impl fmt::Display for HttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HttpError::Http(e) => write!(f, "invalid http message: {e}"),
+            HttpError::Encode(e) => write!(f, "failed to encode body: {e}"),
+            HttpError::UnknownStatus(e) => e.fmt(f),
+            HttpError::MissingMethod => f.write_str("request method missing"),
+        }
+    }
+}
+
+impl crate::compat::error::Error for HttpError {}
+
+impl From<http::Error> for HttpError {
+    fn from(e: http::Error) -> Self {
+        HttpError::Http(e)
+    }
+}
+
+impl From<encode::Error<<Vec<u8> as Write>::Error>> for HttpError {
+    fn from(e: encode::Error<<Vec<u8> as Write>::Error>) -> Self {
+        HttpError::Encode(e)
+    }
+}
+
+impl From<UnknownStatus> for HttpError {
+    fn from(e: UnknownStatus) -> Self {
+        HttpError::UnknownStatus(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn post_with_body_roundtrip() {
+        let req = Request::post("/node/vault")
+            .query("verbose=true")
+            .bearer_auth("t0k3n")
+            .body("hello");
+        let id = req.header().id();
+        let http_req = http::Request::<Bytes>::try_from(req).unwrap();
+        assert_eq!(http::Method::POST, *http_req.method());
+        assert_eq!(http_req.uri(), "/node/vault?verbose=true");
+        assert_eq!(
+            "Bearer t0k3n",
+            http_req.headers()[http::header::AUTHORIZATION]
+        );
+        assert_eq!("hello", minicbor::decode::<&str>(http_req.body()).unwrap());
+
+        let http_res = http::Response::builder()
+            .status(201)
+            .header(REQUEST_ID_HEADER, &http_req.headers()[REQUEST_ID_HEADER])
+            .body(http_req.into_body())
+            .unwrap();
+        let (res, body) = ResponseBuilder::<Bytes>::try_from(http_res)
+            .unwrap()
+            .into_parts();
+        assert_eq!(id, res.re());
+        assert_eq!(Some(Status::Created), res.status());
+        assert!(res.has_body());
+        assert_eq!("hello", minicbor::decode::<&str>(&body.unwrap()).unwrap());
+    }
+
+    #[test]
+    fn missing_method() {
+        let mut req = Request::get("/node").into_parts().0;
+        req.method = None;
+        assert!(matches!(
+            http::Request::<Bytes>::try_from(req),
+            Err(HttpError::MissingMethod)
+        ))
+    }
+
+    #[test]
+    fn unknown_status() {
+        let http_res = http::Response::builder()
+            .status(418)
+            .body(Bytes::new())
+            .unwrap();
+        assert!(matches!(
+            ResponseBuilder::<Bytes>::try_from(http_res),
+            Err(HttpError::UnknownStatus(UnknownStatus(418)))
+        ))
+    }
+}
@@ -0,0 +1,261 @@
+//! Path templates, e.g. "/nodes/:id/services".
+
+use crate::compat::vec::Vec;
+use core::fmt;
+use tinyvec::ArrayVec;
+
+/// The maximum number of named parameters a [`RoutePattern`] may contain.
+pub const MAX_PARAMS: usize = 8;
+
+/// A compiled path template, e.g. "/nodes/:id/services".
+///
+/// Segments starting with ':' are named parameters and match any
+/// single, non-empty path segment. A final '*' segment is a wildcard
+/// which matches the remainder of the path and is captured under the
+/// name "*". All other segments must match literally.
+///
+/// Leading and trailing slashes are not significant. Path segments are
+/// compared and captured as is, i.e. percent-encoded characters are not
+/// decoded.
+#[derive(Debug, Clone)]
+pub struct RoutePattern {
+    segments: Vec<Segment>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Segment {
+    Literal(&'static str),
+    Param(&'static str),
+    Wildcard,
+}
+
+impl RoutePattern {
+    /// Compile a path template.
+    ///
+    /// Fails if the template contains more than [`MAX_PARAMS`] parameters
+    /// or a wildcard which is not the last segment.
+    pub fn new(template: &'static str) -> Result<Self, InvalidRoutePattern> {
+        RoutePattern::check(template)?;
+        let segments = split(template).map(segment).collect();
+        Ok(RoutePattern { segments })
+    }
+
+    /// Check if the given path template is valid.
+    ///
+    /// This is a `const fn`, so templates known at compile time can be
+    /// checked at compile time, as [`route_matcher!`](crate::route_matcher)
+    /// does.
+    pub const fn check(template: &str) -> Result<(), InvalidRoutePattern> {
+        let b = template.as_bytes();
+        let mut params = 0;
+        let mut wildcard = false;
+        let mut i = 0;
+        while i < b.len() {
+            let mut j = i;
+            while j < b.len() && b[j] != b'/' {
+                j += 1
+            }
+            if j > i {
+                if wildcard {
+                    return Err(InvalidRoutePattern::WildcardNotLast);
+                }
+                if b[i] == b':' {
+                    params += 1
+                } else if j - i == 1 && b[i] == b'*' {
+                    params += 1;
+                    wildcard = true
+                }
+            }
+            i = j + 1
+        }
+        if params > MAX_PARAMS {
+            return Err(InvalidRoutePattern::TooManyParams);
+        }
+        Ok(())
+    }
+
+    /// Test if the given path satisfies this pattern.
+    pub fn matches(&self, path: &str) -> bool {
+        match_path(self.segments.iter().copied(), path, |_, _| ())
+    }
+
+    /// Match the given path against this pattern and return the captured
+    /// parameters if successful.
+    pub fn extract<'a>(&self, path: &'a str) -> Option<PathParams<'a>> {
+        let mut params = PathParams::default();
+        let segments = self.segments.iter().copied();
+        if match_path(segments, path, |k, v| params.0.push((k, v))) {
+            Some(params)
+        } else {
+            None
+        }
+    }
+}
+
+/// Check if `path` matches the path template `pattern` without compiling
+/// it into a [`RoutePattern`] first.
+///
+/// The template is assumed to be valid (see [`RoutePattern::check`]).
+pub fn path_matches(pattern: &'static str, path: &str) -> bool {
+    match_path(split(pattern).map(segment), path, |_, _| ())
+}
+
+/// Match `path` segment by segment, passing captured parameters to `capture`.
+fn match_path<'a, I, F>(segments: I, path: &'a str, mut capture: F) -> bool
+where
+    I: Iterator<Item = Segment>,
+    F: FnMut(&'static str, &'a str),
+{
+    let mut rest = trim(path);
+    for s in segments {
+        if let Segment::Wildcard = s {
+            capture("*", rest);
+            return true;
+        }
+        if rest.is_empty() {
+            return false;
+        }
+        let (p, r) = rest.split_once('/').unwrap_or((rest, ""));
+        match s {
+            Segment::Literal(l) if l == p => {}
+            Segment::Param(name) if !p.is_empty() => capture(name, p),
+            _ => return false,
+        }
+        rest = r
+    }
+    rest.is_empty()
+}
+
+fn segment(s: &'static str) -> Segment {
+    match s {
+        "*" => Segment::Wildcard,
+        s => match s.strip_prefix(':') {
+            Some(name) => Segment::Param(name),
+            None => Segment::Literal(s),
+        },
+    }
+}
+
+fn trim(path: &str) -> &str {
+    path.trim_start_matches('/').trim_end_matches('/')
+}
+
+fn split(path: &str) -> impl Iterator<Item = &str> {
+    trim(path).split('/').filter(|s| !s.is_empty())
+}
+
+/// Named path parameters captured by a [`RoutePattern`].
+#[derive(Debug, Clone, Default)]
+pub struct PathParams<'a>(ArrayVec<[(&'static str, &'a str); MAX_PARAMS]>);
+
+impl<'a> PathParams<'a> {
+    /// Get the value of the parameter with the given name.
+    pub fn get(&self, name: &str) -> Option<&'a str> {
+        self.0.iter().find(|(k, _)| *k == name).map(|(_, v)| *v)
+    }
+
+    /// Iterate over all `(name, value)` pairs in pattern order.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &'a str)> + '_ {
+        self.0.iter().copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// Error returned when a path template is not a valid [`RoutePattern`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InvalidRoutePattern {
+    /// The template contains more than [`MAX_PARAMS`] parameters.
+    TooManyParams,
+    /// The template contains a wildcard which is not the last segment.
+    WildcardNotLast,
+}
+
+impl fmt::Display for InvalidRoutePattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InvalidRoutePattern::TooManyParams => f.write_str("too many parameters"),
+            InvalidRoutePattern::WildcardNotLast => f.write_str("wildcard is not the last segment"),
+        }
+    }
+}
+
+impl crate::compat::error::Error for InvalidRoutePattern {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pattern(template: &'static str) -> RoutePattern {
+        let p = RoutePattern::new(template).unwrap();
+        // Matching without compiling the template must agree:
+        for path in ["", "/", "/nodes/n1", "/nodes/n1/services", "/static/a/b"] {
+            assert_eq!(p.matches(path), path_matches(template, path))
+        }
+        p
+    }
+
+    #[test]
+    fn literal_and_params() {
+        let p = pattern("/nodes/:id/secure-channels/:channel_id");
+        let m = p.extract("/nodes/n1/secure-channels/c2").unwrap();
+        assert_eq!(Some("n1"), m.get("id"));
+        assert_eq!(Some("c2"), m.get("channel_id"));
+        assert_eq!(2, m.len());
+        assert!(!p.matches("/nodes/n1/secure-channels"));
+        assert!(!p.matches("/nodes/n1/secure-channels/c2/x"));
+        assert!(!p.matches("/node/n1/secure-channels/c2"))
+    }
+
+    #[test]
+    fn trailing_slash() {
+        let p = pattern("/nodes/:id/");
+        assert!(p.matches("/nodes/n1"));
+        assert!(p.matches("/nodes/n1/"));
+        assert!(p.matches("nodes/n1"))
+    }
+
+    #[test]
+    fn empty_segments() {
+        let p = pattern("/nodes/:id/services");
+        assert!(!p.matches("/nodes//services"));
+        assert!(!p.matches("/nodes/n1//services"))
+    }
+
+    #[test]
+    fn percent_encoding() {
+        let p = pattern("/nodes/:id");
+        let m = p.extract("/nodes/a%2Fb").unwrap();
+        assert_eq!(Some("a%2Fb"), m.get("id"));
+        assert!(!pattern("/a b").matches("/a%20b"))
+    }
+
+    #[test]
+    fn wildcard() {
+        let p = pattern("/static/*");
+        assert_eq!(Some("a/b/c"), p.extract("/static/a/b/c").unwrap().get("*"));
+        assert_eq!(Some(""), p.extract("/static").unwrap().get("*"));
+        assert!(!p.matches("/other/a"))
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            InvalidRoutePattern::WildcardNotLast,
+            RoutePattern::new("/static/*/x").unwrap_err()
+        );
+        assert_eq!(
+            InvalidRoutePattern::TooManyParams,
+            RoutePattern::new("/:a/:b/:c/:d/:e/:f/:g/:h/:i").unwrap_err()
+        );
+        assert!(RoutePattern::check("/:a/:b/:c/:d/:e/:f/:g/*").is_ok());
+        assert!(RoutePattern::check("//static//*//").is_ok())
+    }
+}
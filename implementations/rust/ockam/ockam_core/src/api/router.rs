@@ -0,0 +1,82 @@
+//! Declarative request routing, see [`route_matcher!`](crate::route_matcher).
+
+/// Generate a dispatch function from a table of routes.
+///
+/// Each route is a triple of a [`Method`], a path template (see
+/// [`RoutePattern`]) and a handler of type
+/// `fn(&Request, &[u8]) -> Result<Vec<u8>>`. Routes are tried in order
+/// and the first one whose method and path match is called.
+///
+/// If no route matches, the generated function responds with
+/// `404 Not Found`, or with `405 Method Not Allowed` if some path
+/// matched but with a different method.
+///
+/// By default the function is called `dispatch`, but visibility and name
+/// can be given explicitly:
+///
+/// ```
+/// use ockam_core::api::{Method, Request, Response};
+/// use ockam_core::{route_matcher, Result};
+///
+/// fn health(req: &Request, _body: &[u8]) -> Result<Vec<u8>> {
+///     Ok(Response::ok(req.id()).to_vec()?)
+/// }
+///
+/// route_matcher! {
+///     pub fn node_routes {
+///         (Method::Get, "/node/health", health),
+///     }
+/// }
+/// ```
+///
+/// Templates are checked at compile time, so invalid ones are rejected:
+///
+/// ```compile_fail
+/// # use ockam_core::api::{Method, Request, Response};
+/// # use ockam_core::{route_matcher, Result};
+/// # fn files(req: &Request, _body: &[u8]) -> Result<Vec<u8>> {
+/// #     Ok(Response::ok(req.id()).to_vec()?)
+/// # }
+/// route_matcher! {
+///     (Method::Get, "/static/*/files", files),
+/// }
+/// ```
+///
+/// [`Method`]: crate::api::Method
+/// [`RoutePattern`]: crate::api::RoutePattern
+#[macro_export]
+macro_rules! route_matcher {
+    ($vis:vis fn $name:ident { $(($method:expr, $pattern:expr, $handler:expr)),+ $(,)? }) => {
+        $vis fn $name(
+            req: &$crate::api::Request<'_>,
+            body: &[u8],
+        ) -> $crate::Result<$crate::compat::vec::Vec<u8>> {
+            $(
+                const _: () = assert!(
+                    $crate::api::RoutePattern::check($pattern).is_ok(),
+                    "invalid route pattern"
+                );
+            )+
+            let mut path_matched = false;
+            $(
+                if $crate::api::path_matches($pattern, req.path()) {
+                    if req.method() == Some($method) {
+                        return $handler(req, body);
+                    }
+                    path_matched = true;
+                }
+            )+
+            if path_matched {
+                Ok($crate::api::invalid_method(req).to_vec()?)
+            } else {
+                let e = $crate::api::Error::new(req.path()).with_message("unknown path");
+                Ok($crate::api::Response::not_found(req.id()).body(e).to_vec()?)
+            }
+        }
+    };
+    ($(($method:expr, $pattern:expr, $handler:expr)),+ $(,)?) => {
+        $crate::route_matcher! {
+            fn dispatch { $(($method, $pattern, $handler)),+ }
+        }
+    };
+}
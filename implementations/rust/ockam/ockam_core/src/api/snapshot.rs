@@ -0,0 +1,134 @@
+//! Owned copies of requests, e.g. for audit logs and replay.
+
+use super::{Cbor, Explicit, Request, RequestBuilder};
+use crate::compat::vec::Vec;
+use core::marker::PhantomData;
+use minicbor::encode::{self, Encoder, Write};
+use minicbor::{decode, Decode, Decoder, Encode};
+
+/// A complete, owned copy of a request header and its body.
+#[derive(Debug, Clone)]
+pub struct RequestSnapshot {
+    pub header: Request<'static>,
+    /// The CBOR-encoded body, i.e. the bytes following the header, empty
+    /// if the request has no body.
+    pub body: Vec<u8>,
+    /// Milliseconds since the Unix epoch.
+    pub received_at: u64,
+}
+
+impl RequestSnapshot {
+    /// Copy the given request header and encoded body, received at `now`.
+    pub fn capture(header: &Request<'_>, body: &[u8], now: u64) -> Self {
+        RequestSnapshot {
+            header: header.clone().into_owned(),
+            body: body.to_vec(),
+            received_at: now,
+        }
+    }
+
+    /// Reconstruct a builder of the captured request.
+    ///
+    /// Header fields, including the request ID, are kept as they were and
+    /// the body is written back as is, so the replayed request encodes to
+    /// the same bytes as the original.
+    pub fn replay(&self) -> RequestBuilder<'static, Cbor<'_>> {
+        let body = if self.header.has_body() {
+            Some(Cbor(&self.body))
+        } else {
+            None
+        };
+        RequestBuilder {
+            header: self.header.clone(),
+            body,
+            explicit: Explicit {
+                id: true,
+                method: self.header.method().is_some(),
+                path: true,
+            },
+            state: PhantomData,
+        }
+    }
+}
+
+impl<C> Encode<C> for RequestSnapshot {
+    fn encode<W: Write>(
+        &self,
+        e: &mut Encoder<W>,
+        ctx: &mut C,
+    ) -> Result<(), encode::Error<W::Error>> {
+        e.map(3)?.u8(1)?;
+        self.header.encode(e, ctx)?;
+        e.u8(2)?.bytes(&self.body)?.u8(3)?.u64(self.received_at)?;
+        Ok(())
+    }
+}
+
+impl<'b, C> Decode<'b, C> for RequestSnapshot {
+    fn decode(d: &mut Decoder<'b>, ctx: &mut C) -> Result<Self, decode::Error> {
+        let len = d
+            .map()?
+            .ok_or_else(|| decode::Error::message("indefinite request snapshot map"))?;
+        let mut header = None;
+        let mut body = None;
+        let mut received_at = None;
+        for _ in 0..len {
+            match d.u8()? {
+                1 => header = Some(Request::decode(d, ctx)?.into_owned()),
+                2 => body = Some(d.bytes()?.to_vec()),
+                3 => received_at = Some(d.u64()?),
+                _ => d.skip()?,
+            }
+        }
+        match (header, body, received_at) {
+            (Some(header), Some(body), Some(received_at)) => Ok(RequestSnapshot {
+                header,
+                body,
+                received_at,
+            }),
+            _ => Err(decode::Error::message("incomplete request snapshot")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::parse_request;
+
+    #[test]
+    fn capture_and_replay() {
+        let original = Request::post("/node/message")
+            .query("verbose=true")
+            .idempotency_key("k1")
+            .body(["hello", "world"])
+            .to_vec()
+            .unwrap();
+
+        let (req, rest) = parse_request(&original).unwrap();
+        let snapshot = RequestSnapshot::capture(&req, rest, 1663000000000);
+
+        let bytes = minicbor::to_vec(&snapshot).unwrap();
+        let snapshot: RequestSnapshot = decode(&bytes).unwrap();
+        assert_eq!(1663000000000, snapshot.received_at);
+        assert_eq!(rest, &snapshot.body[..]);
+
+        let replayed = snapshot.replay().to_vec().unwrap();
+        assert_eq!(original, replayed);
+        let (req, rest) = parse_request(&replayed).unwrap();
+        let body: [&str; 2] = req.decode_body(rest).unwrap();
+        assert_eq!(["hello", "world"], body)
+    }
+
+    #[test]
+    fn without_body() {
+        let original = Request::get("/nodes").to_vec().unwrap();
+        let (req, _) = parse_request(&original).unwrap();
+        let snapshot = RequestSnapshot::capture(&req, &[], 0);
+        assert!(snapshot.replay().into_parts().1.is_none());
+
+        let bytes = minicbor::to_vec(&snapshot).unwrap();
+        let snapshot: RequestSnapshot = decode(&bytes).unwrap();
+        assert_eq!(original, snapshot.replay().to_vec().unwrap())
+    }
+}
@@ -0,0 +1,74 @@
+//! Helpers for testing API clients and services.
+
+use core::fmt::{self, Write as _};
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Record};
+use tracing::{subscriber, Event, Id, Level, Metadata, Subscriber};
+
+/// A tracing subscriber which records every span and event.
+///
+/// Fields are recorded as space-separated `name=value` pairs, using the
+/// `Debug` representation of each value.
+#[derive(Debug, Clone, Default)]
+pub struct Recorder {
+    spans: Arc<Mutex<Vec<(&'static str, String)>>>,
+    events: Arc<Mutex<Vec<(Level, String)>>>,
+}
+
+impl Recorder {
+    /// Call `f` with this recorder as the default subscriber.
+    pub fn with_default<R>(&self, f: impl FnOnce() -> R) -> R {
+        subscriber::with_default(self.clone(), f)
+    }
+
+    /// The name and fields of every span created so far.
+    pub fn spans(&self) -> Vec<(&'static str, String)> {
+        self.spans.lock().unwrap().clone()
+    }
+
+    /// The level and fields of every event emitted so far.
+    pub fn events(&self) -> Vec<(Level, String)> {
+        self.events.lock().unwrap().clone()
+    }
+}
+
+struct Fields<'a>(&'a mut String);
+
+impl Visit for Fields<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        let _ = write!(self.0, "{}={:?} ", field.name(), value);
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, attrs: &Attributes<'_>) -> Id {
+        let mut spans = self.spans.lock().unwrap();
+        let mut fields = String::new();
+        attrs.record(&mut Fields(&mut fields));
+        spans.push((attrs.metadata().name(), fields));
+        Id::from_u64(spans.len() as u64)
+    }
+
+    fn record(&self, id: &Id, values: &Record<'_>) {
+        let mut spans = self.spans.lock().unwrap();
+        let (_, fields) = &mut spans[id.into_u64() as usize - 1];
+        values.record(&mut Fields(fields))
+    }
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut fields = String::new();
+        event.record(&mut Fields(&mut fields));
+        let level = *event.metadata().level();
+        self.events.lock().unwrap().push((level, fields))
+    }
+
+    fn enter(&self, _: &Id) {}
+    fn exit(&self, _: &Id) {}
+}
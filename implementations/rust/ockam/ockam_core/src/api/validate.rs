@@ -0,0 +1,108 @@
+//! Runtime validation of CBOR items against [`SCHEMA`].
+
+use super::SCHEMA;
+use crate::compat::string::{String, ToString};
+use core::fmt;
+
+/// The top-level types defined in [`SCHEMA`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchemaKind {
+    Request,
+    Response,
+    Error,
+}
+
+impl SchemaKind {
+    /// The name of the CDDL rule of this kind.
+    pub fn rule(self) -> &'static str {
+        match self {
+            SchemaKind::Request => "request",
+            SchemaKind::Response => "response",
+            SchemaKind::Error => "error",
+        }
+    }
+}
+
+/// A CBOR item did not match the schema.
+#[derive(Debug, Clone)]
+pub struct SchemaError {
+    kind: SchemaKind,
+    message: String,
+}
+
+impl SchemaError {
+    pub fn kind(&self) -> SchemaKind {
+        self.kind
+    }
+
+    /// A description of the mismatch.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid {}: {}", self.kind.rule(), self.message)
+    }
+}
+
+impl crate::compat::error::Error for SchemaError {}
+
+/// Validate a single CBOR item against the schema of the given kind.
+///
+/// For requests and responses `bytes` must only contain the header,
+/// not the body.
+pub fn validate_schema(kind: SchemaKind, bytes: &[u8]) -> Result<(), SchemaError> {
+    cddl_cat::validate_cbor_bytes(kind.rule(), SCHEMA, bytes).map_err(|e| SchemaError {
+        kind,
+        message: e.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{Error, Id, Request, Response, Status};
+
+    #[test]
+    fn valid() {
+        let req = Request::get("/nodes").query("limit=10").to_vec().unwrap();
+        validate_schema(SchemaKind::Request, &req).unwrap();
+        let res = Response::ok(Id::ZERO).to_vec().unwrap();
+        validate_schema(SchemaKind::Response, &res).unwrap();
+        let err = Error::new("/nodes")
+            .with_message("bad request")
+            .with_status(Status::BadRequest);
+        let err = minicbor::to_vec(&err).unwrap();
+        validate_schema(SchemaKind::Error, &err).unwrap()
+    }
+
+    #[test]
+    fn invalid() {
+        let text = minicbor::to_vec("hello").unwrap();
+        for kind in [SchemaKind::Request, SchemaKind::Response, SchemaKind::Error] {
+            let e = validate_schema(kind, &text).unwrap_err();
+            assert_eq!(kind, e.kind());
+            assert!(!e.message().is_empty());
+            assert!(e
+                .to_string()
+                .starts_with(&format!("invalid {}: ", kind.rule())))
+        }
+
+        // A request is not a response:
+        let req = Request::get("/nodes").to_vec().unwrap();
+        assert!(validate_schema(SchemaKind::Response, &req).is_err());
+
+        // The error path has to be text:
+        let mut err = Vec::new();
+        minicbor::Encoder::new(&mut err)
+            .map(1)
+            .unwrap()
+            .u8(1)
+            .unwrap()
+            .u8(42)
+            .unwrap();
+        assert!(validate_schema(SchemaKind::Error, &err).is_err())
+    }
+}
@@ -5,19 +5,30 @@ use core::ops::Deref;
 use minicbor::{Decode, Encode};
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "api-serde")]
+use crate::compat::string::String;
+#[cfg(feature = "api-serde")]
+use core::fmt;
+#[cfg(feature = "api-serde")]
+use serde::de::{self, SeqAccess, Visitor};
+#[cfg(feature = "api-serde")]
+use serde::{Deserializer, Serializer};
+
 /// A new type around `Cow<'_, [u8]>` that borrows from input.
 ///
 /// Contrary to `Cow<_, [u8]>` the `Decode` impl for this type will always borrow
 /// from input so using it in types like `Option`, `Vec<_>` etc will not produce
 /// owned element values.
-#[derive(
-    Debug, Clone, Encode, Decode, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash,
-)]
+///
+/// With the "api-serde" feature, human-readable serde formats like JSON
+/// represent the bytes as a base64 string.
+#[derive(Debug, Clone, Encode, Decode, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(not(feature = "api-serde"), derive(Serialize, Deserialize))]
 #[cbor(transparent)]
-#[serde(transparent)]
+#[cfg_attr(not(feature = "api-serde"), serde(transparent))]
 pub struct CowBytes<'a>(
     #[cbor(b(0), with = "minicbor::bytes")]
-    #[serde(borrow)]
+    #[cfg_attr(not(feature = "api-serde"), serde(borrow))]
     pub Cow<'a, [u8]>,
 );
 
@@ -36,6 +47,38 @@ impl CowBytes<'_> {
     pub fn into_owned(self) -> Vec<u8> {
         self.0.into_owned()
     }
+
+    /// Turn into a `'static` CowBytes, cloning the data if it is borrowed.
+    pub fn into_static(self) -> CowBytes<'static> {
+        CowBytes(Cow::Owned(self.0.into_owned()))
+    }
+
+    /// Borrow the byte slice.
+    pub fn as_slice(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl<'a> CowBytes<'a> {
+    /// Divide into two at an index, like `[u8]::split_at`.
+    ///
+    /// Borrowed data is split without copying, owned data is cloned.
+    ///
+    /// # Panics
+    ///
+    /// If `mid > self.len()`.
+    pub fn split_at(&self, mid: usize) -> (CowBytes<'a>, CowBytes<'a>) {
+        match &self.0 {
+            Cow::Borrowed(b) => {
+                let (l, r) = b.split_at(mid);
+                (CowBytes(Cow::Borrowed(l)), CowBytes(Cow::Borrowed(r)))
+            }
+            Cow::Owned(v) => {
+                let (l, r) = v.split_at(mid);
+                (l.to_vec().into(), r.to_vec().into())
+            }
+        }
+    }
 }
 
 impl<'a> From<&'a [u8]> for CowBytes<'a> {
@@ -63,3 +106,141 @@ impl<'a> Deref for CowBytes<'a> {
         &self.0
     }
 }
+
+impl<'a, S: ?Sized + AsRef<[u8]>> PartialEq<S> for CowBytes<'a> {
+    fn eq(&self, other: &S) -> bool {
+        self.0 == other.as_ref()
+    }
+}
+
+impl<'a> AsRef<[u8]> for CowBytes<'a> {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+#[cfg(feature = "api-serde")]
+impl Serialize for CowBytes<'_> {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        if s.is_human_readable() {
+            s.serialize_str(&base64::encode(&self.0))
+        } else {
+            self.0.serialize(s)
+        }
+    }
+}
+
+#[cfg(feature = "api-serde")]
+impl<'de: 'a, 'a> Deserialize<'de> for CowBytes<'a> {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        if d.is_human_readable() {
+            let s = String::deserialize(d)?;
+            let b = base64::decode(s).map_err(de::Error::custom)?;
+            Ok(CowBytes(Cow::Owned(b)))
+        } else {
+            d.deserialize_bytes(BytesVisitor)
+        }
+    }
+}
+
+/// Visits the bytes of a `CowBytes`, borrowing them if possible.
+#[cfg(feature = "api-serde")]
+struct BytesVisitor;
+
+#[cfg(feature = "api-serde")]
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = CowBytes<'de>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a byte array")
+    }
+
+    fn visit_borrowed_bytes<E: de::Error>(self, v: &'de [u8]) -> Result<Self::Value, E> {
+        Ok(CowBytes(Cow::Borrowed(v)))
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Ok(CowBytes(Cow::Owned(v.to_vec())))
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(CowBytes(Cow::Owned(v)))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut v = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(b) = seq.next_element()? {
+            v.push(b)
+        }
+        Ok(CowBytes(Cow::Owned(v)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_at() {
+        let bytes = [0, 0, 0, 3, 1, 2, 3];
+        let b = CowBytes::from(&bytes[..]);
+        let (len, rest) = b.split_at(4);
+        assert!(len.is_borrowed() && rest.is_borrowed());
+        assert_eq!(&bytes[..4], &*len);
+        assert_eq!(&bytes[4..], &*rest);
+        assert_eq!(bytes.to_vec(), [&len[..], &rest[..]].concat());
+
+        let o = CowBytes::from(bytes.to_vec());
+        let (l, r) = o.split_at(7);
+        assert!(!l.is_borrowed() && !r.is_borrowed());
+        assert_eq!(&bytes[..], &*l);
+        assert!(r.is_empty());
+    }
+
+    #[test]
+    fn as_slice_and_as_ref() {
+        let bytes = [1, 2, 3];
+        let b = CowBytes::from(&bytes[..]);
+        let o = CowBytes::from(bytes.to_vec());
+        assert!(b.is_borrowed() && !o.is_borrowed());
+        for c in [&b, &o] {
+            assert_eq!(&bytes[..], c.as_slice());
+            assert_eq!(&bytes[..], AsRef::<[u8]>::as_ref(c))
+        }
+        assert_eq!(bytes.as_ptr(), b.as_slice().as_ptr());
+        assert!(CowBytes::from(Vec::new()).as_slice().is_empty())
+    }
+
+    #[test]
+    fn equality() {
+        let bytes = [1, 2, 3];
+        let b = CowBytes::from(&bytes[..]);
+        let o = CowBytes::from(bytes.to_vec());
+
+        // Borrowed and owned values are equal if their bytes are:
+        assert_eq!(b, o);
+        assert_eq!(o, b);
+        assert_ne!(b, CowBytes::from(vec![1, 2]));
+        assert_ne!(o, CowBytes::from(&bytes[1..]));
+
+        // Comparison with other byte containers:
+        for c in [&b, &o] {
+            assert_eq!(*c, bytes);
+            assert_eq!(*c, bytes[..]);
+            assert_eq!(*c, bytes.to_vec());
+            assert_ne!(*c, [3u8, 2, 1]);
+            assert_ne!(*c, Vec::<u8>::new())
+        }
+    }
+
+    #[cfg(feature = "api-serde")]
+    #[test]
+    fn serde_json_base64() {
+        let b = CowBytes::from(&b"hello"[..]);
+        let json = serde_json::to_string(&b).unwrap();
+        assert_eq!(r#""aGVsbG8=""#, json);
+        let d: CowBytes = serde_json::from_str(&json).unwrap();
+        assert_eq!(b, d);
+        assert!(serde_json::from_str::<CowBytes>(r#""not base64!""#).is_err())
+    }
+}
@@ -38,6 +38,16 @@ impl CowStr<'_> {
     pub fn into_owned(self) -> String {
         self.0.into_owned()
     }
+
+    /// Turn into a `'static` CowStr, cloning the data if it is borrowed.
+    pub fn into_static(self) -> CowStr<'static> {
+        CowStr(Cow::Owned(self.0.into_owned()))
+    }
+
+    /// Borrow the string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
 }
 
 impl<'a> From<&'a str> for CowStr<'a> {
@@ -12,6 +12,16 @@ use zeroize::Zeroize;
 #[derive(Clone, Copy, Default, PartialEq, Eq, Zeroize)]
 pub struct TypeTag<const N: usize>;
 
+impl<const N: usize> TypeTag<N> {
+    /// The numeric tag value.
+    pub const VALUE: usize = N;
+
+    /// Get the numeric tag value.
+    pub fn value(&self) -> usize {
+        N
+    }
+}
+
 // Custom `Debug` impl to include the tag number.
 impl<const N: usize> fmt::Debug for TypeTag<N> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -39,3 +49,32 @@ impl<'b, C, const N: usize> Decode<'b, C> for TypeTag<N> {
         Err(decode::Error::message(msg))
     }
 }
+
+/// A type which is identified by a [`TypeTag`].
+pub trait Tagged {
+    /// The numeric tag value.
+    const TAG: usize;
+}
+
+impl<const N: usize> Tagged for TypeTag<N> {
+    const TAG: usize = N;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{Request, Response};
+
+    #[test]
+    fn tag_value() {
+        assert_eq!(7586022, TypeTag::<7586022>::VALUE);
+        assert_eq!(TypeTag::<7586022>::VALUE, TypeTag::<7586022>.value())
+    }
+
+    #[test]
+    fn tagged() {
+        assert_eq!(7586022, Request::TAG);
+        assert_eq!(9750358, Response::TAG);
+        assert_eq!(TypeTag::<7586022>::TAG, Request::TAG)
+    }
+}
@@ -0,0 +1,105 @@
+use minicbor::Decoder;
+use ockam_core::api::{Method, Request, Response, Status};
+use ockam_core::{route_matcher, Result};
+
+fn reply(req: &Request, name: &str) -> Result<Vec<u8>> {
+    Ok(Response::ok(req.id()).body(name).to_vec()?)
+}
+
+fn handle_health(req: &Request, _: &[u8]) -> Result<Vec<u8>> {
+    reply(req, "health")
+}
+
+fn handle_list_tcp(req: &Request, _: &[u8]) -> Result<Vec<u8>> {
+    reply(req, "list_tcp")
+}
+
+fn handle_create_tcp(req: &Request, _: &[u8]) -> Result<Vec<u8>> {
+    reply(req, "create_tcp")
+}
+
+fn handle_delete_tcp(req: &Request, _: &[u8]) -> Result<Vec<u8>> {
+    reply(req, "delete_tcp")
+}
+
+fn handle_echo(req: &Request, body: &[u8]) -> Result<Vec<u8>> {
+    reply(req, minicbor::decode(body)?)
+}
+
+fn handle_static(req: &Request, _: &[u8]) -> Result<Vec<u8>> {
+    reply(req, "static")
+}
+
+route_matcher! {
+    (Method::Get,    "/node/health",             handle_health),
+    (Method::Get,    "/node/tcp/connection",     handle_list_tcp),
+    (Method::Post,   "/node/tcp/connection",     handle_create_tcp),
+    (Method::Delete, "/node/tcp/connection/:id", handle_delete_tcp),
+    (Method::Post,   "/node/echo",               handle_echo),
+    (Method::Get,    "/static/*",                handle_static),
+}
+
+/// Dispatch a request and return the response status and string body, if any.
+fn call(method: Method, path: &str, body: &[u8]) -> (Status, Option<String>) {
+    let req = Request::builder(method, path);
+    let bytes = dispatch(req.header(), body).unwrap();
+    let mut dec = Decoder::new(&bytes);
+    let res: Response = dec.decode().unwrap();
+    assert_eq!(req.header().id(), res.re());
+    let body = if res.has_body() && res.status() == Some(Status::Ok) {
+        dec.decode::<&str>().ok().map(String::from)
+    } else {
+        None
+    };
+    (res.status().unwrap(), body)
+}
+
+#[test]
+fn routes() {
+    for (method, path, name) in [
+        (Method::Get, "/node/health", "health"),
+        (Method::Get, "/node/tcp/connection", "list_tcp"),
+        (Method::Post, "/node/tcp/connection", "create_tcp"),
+        (Method::Delete, "/node/tcp/connection/c1", "delete_tcp"),
+        (Method::Delete, "/node/tcp/connection/c1/", "delete_tcp"),
+        (Method::Get, "/static", "static"),
+        (Method::Get, "/static/a/b/c", "static"),
+    ] {
+        assert_eq!(
+            (Status::Ok, Some(name.to_string())),
+            call(method, path, b"")
+        )
+    }
+}
+
+#[test]
+fn body() {
+    let body = minicbor::to_vec("hello").unwrap();
+    let (status, echo) = call(Method::Post, "/node/echo", &body);
+    assert_eq!(Status::Ok, status);
+    assert_eq!(Some("hello".to_string()), echo)
+}
+
+#[test]
+fn not_found() {
+    for (method, path) in [
+        (Method::Get, "/node"),
+        (Method::Get, "/node/healthy"),
+        (Method::Delete, "/node/tcp/connection/c1/x"),
+        (Method::Delete, "/node/tcp/connection//x"),
+        (Method::Get, "/cloud/health"),
+    ] {
+        assert_eq!((Status::NotFound, None), call(method, path, b""))
+    }
+}
+
+#[test]
+fn method_not_allowed() {
+    for (method, path) in [
+        (Method::Post, "/node/health"),
+        (Method::Delete, "/node/tcp/connection"),
+        (Method::Get, "/node/tcp/connection/c1"),
+    ] {
+        assert_eq!((Status::MethodNotAllowed, None), call(method, path, b""))
+    }
+}